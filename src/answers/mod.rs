@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Part};
+use crate::utils;
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+const DAY_TABLE_PREFIX: &str = "day";
+const PART_KEY_PREFIX: &str = "part";
+const COMMENT_START: char = '#';
+const STRING_QUOTE: char = '"';
+
+// How a computed answer compares to the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+impl Verdict {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+// Known-correct answers per day and part, read from a small subset of TOML:
+//
+//     [day1]
+//     part1 = 1097
+//     part2 = "a text answer"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    known: BTreeMap<(usize, usize), Answer>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::parse(&utils::read_input_file(path)?)
+    }
+
+    pub fn parse(raw_answers: &str) -> Result<Self> {
+        Self::parse_with_day(raw_answers, None)
+    }
+
+    // Sidecar answers of a single example may skip the `[day<N>]` table
+    pub fn parse_for_day(raw_answers: &str, day: usize) -> Result<Self> {
+        Self::parse_with_day(raw_answers, Some(day))
+    }
+
+    fn parse_with_day(raw_answers: &str, default_day: Option<usize>) -> Result<Self> {
+        let mut known = BTreeMap::new();
+        let mut current_day = default_day;
+
+        for (line_number, line) in utils::numbered_lines(raw_answers) {
+            let indent = line.len() - line.trim_start().len();
+            let line = strip_comment(line.trim_start());
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let day = table
+                    .strip_suffix(']')
+                    .and_then(|table| table.trim().strip_prefix(DAY_TABLE_PREFIX))
+                    .and_then(|day| day.parse::<usize>().ok())
+                    .ok_or_else(|| {
+                        Error::malformed(line_number, indent + 1, "expected a `[day<N>]` table")
+                    })?;
+                current_day = Some(day);
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(Error::malformed(
+                    line_number,
+                    indent + 1,
+                    "expected `part<N> = <answer>`",
+                ));
+            };
+            let part = key
+                .trim()
+                .strip_prefix(PART_KEY_PREFIX)
+                .and_then(|part| part.parse::<usize>().ok())
+                .filter(|part| Part::ALL.iter().any(|known| known.number() == *part))
+                .ok_or_else(|| {
+                    Error::malformed(line_number, indent + 1, "expected `part1` or `part2`")
+                })?;
+            let day = current_day.ok_or_else(|| {
+                Error::malformed(
+                    line_number,
+                    indent + 1,
+                    "answer outside of a `[day<N>]` table",
+                )
+            })?;
+            let value_column =
+                indent + key.len() + 1 + (value.len() - value.trim_start().len()) + 1;
+            let answer = parse_value(value.trim())
+                .ok_or_else(|| Error::malformed(line_number, value_column, "invalid answer"))?;
+            known.insert((day, part), answer);
+        }
+
+        Ok(Answers { known })
+    }
+
+    pub fn expected(&self, day: usize, part: Part) -> Option<&Answer> {
+        self.known.get(&(day, part.number()))
+    }
+
+    pub fn verdict(&self, day: usize, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(day, part) {
+            // Compare rendered values so `Unsigned(3)` and `Signed(3)` agree
+            Some(expected) if expected.to_string() == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '\\' if in_string => escaped = !escaped,
+            STRING_QUOTE if !escaped => in_string = !in_string,
+            COMMENT_START if !in_string => return line[..index].trim_end(),
+            _ => escaped = false,
+        }
+    }
+    line.trim_end()
+}
+
+fn parse_value(raw_value: &str) -> Option<Answer> {
+    if let Some(quoted) = raw_value.strip_prefix(STRING_QUOTE) {
+        let quoted = quoted.strip_suffix(STRING_QUOTE)?;
+        let mut text = String::with_capacity(quoted.len());
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next()? {
+                    'n' => text.push('\n'),
+                    't' => text.push('\t'),
+                    escaped @ ('\\' | '"') => text.push(escaped),
+                    _ => return None,
+                },
+                STRING_QUOTE => return None,
+                c => text.push(c),
+            }
+        }
+        return Some(Answer::Text(text));
+    }
+
+    // TOML integers may use `_` as a digit separator
+    let digits = raw_value.replace('_', "");
+    if let Ok(value) = digits.parse::<u64>() {
+        Some(Answer::Unsigned(value))
+    } else {
+        digits.parse::<i64>().ok().map(Answer::Signed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+# Answers of the examples
+[day1]
+part1 = 3
+part2 = 6 # trailing comment
+
+[day2]
+part1 = 1_227_775_554
+part2 = "a # b \"c\""
+"#;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.expected(1, Part::One), Some(&Answer::Unsigned(3)));
+        assert_eq!(answers.expected(1, Part::Two), Some(&Answer::Unsigned(6)));
+        assert_eq!(
+            answers.expected(2, Part::One),
+            Some(&Answer::Unsigned(1227775554))
+        );
+        assert_eq!(
+            answers.expected(2, Part::Two),
+            Some(&Answer::Text("a # b \"c\"".to_string()))
+        );
+        assert_eq!(answers.expected(3, Part::One), None);
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(
+            answers.verdict(1, Part::One, &Answer::Signed(3)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.verdict(1, Part::Two, &Answer::Unsigned(7)),
+            Verdict::Wrong {
+                expected: Answer::Unsigned(6)
+            }
+        );
+        assert_eq!(
+            answers.verdict(5, Part::One, &Answer::Unsigned(7)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn test_parse_sidecar_answers() {
+        let answers = Answers::parse_for_day("part1 = 357\n", 3).unwrap();
+        assert_eq!(answers.expected(3, Part::One), Some(&Answer::Unsigned(357)));
+        assert_eq!(answers.expected(3, Part::Two), None);
+    }
+
+    #[test]
+    fn test_malformed_answers() {
+        let error = Answers::parse("part1 = 3").unwrap_err();
+        assert!(matches!(error, Error::MalformedLine { line: 1, .. }));
+        let error = Answers::parse("[day1]\npart3 = 3").unwrap_err();
+        assert!(matches!(error, Error::MalformedLine { line: 2, .. }));
+        let error = Answers::parse("[day1]\npart1 = \"open").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MalformedLine {
+                line: 2,
+                column: 9,
+                ..
+            }
+        ));
+    }
+}
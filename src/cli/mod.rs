@@ -0,0 +1,463 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::answers::ANSWERS_FILE_NAME;
+use crate::puzzles;
+use crate::report::{Format, UnknownFormat};
+use crate::solution::Part;
+use crate::utils::{self, InputSource};
+
+const ALL_DAYS_KEYWORD: &str = "all";
+const INCLUSIVE_RANGE_DELIMITER: &str = "..=";
+const EXCLUSIVE_RANGE_DELIMITER: &str = "..";
+const LIST_DELIMITER: char = ',';
+const STDIN_PATH: &str = "-";
+
+pub const USAGE: &str = "\
+Usage: aoc [COMMAND]
+
+Commands:
+  run <DAYS> [OPTIONS]   Solve the selected days (default: all)
+  examples <DAYS>        Solve and check every example of the selected days (default: all)
+  scaffold <DAY>         Generate the module, registry entry, input and example files of a new day
+  help                   Print this message
+
+Run options:
+  -p, --part <1|2>           Only solve the given part
+  -f, --format <FORMAT>      Output format: pretty (default), plain, json or csv
+  -d, --input-dir <DIR>      Read `day<N>.txt` inputs from DIR
+  -i, --input <PATH>         Read the input of a single day from PATH (`-` for stdin)
+      --stdin                Read the input of a single day from stdin
+  -t, --time                 Print parse and solve durations after the answers
+  -r, --repeat <N>           Time every step over N runs (implies --time)
+  -c, --check                Compare answers with `answers.toml` from the input directory
+  -a, --answers <PATH>       Compare answers with the given answers file (implies --check)
+
+DAYS can be a single day (4), an inclusive range (1..=3), an exclusive
+range (1..4), a comma separated list (1,3,5) or the keyword `all`.
+
+The input directory defaults to $AOC_INPUT_DIR, then to the
+`puzzles_inputs/real` directory of this repository.
+
+Examples are read from `puzzles_inputs/examples/day<N>/*.txt`, with the
+expected answers of `<name>.txt` in an optional `<name>.toml` next to it.
+
+With --check, the exit status is non-zero when an answer is wrong.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    pub days: Vec<usize>,
+    pub part: Option<Part>,
+    pub format: Format,
+    pub source: InputSource,
+    pub time: bool,
+    pub runs: usize,
+    // Answers file to check the results against, if any
+    pub check: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(RunOptions),
+    Examples(Vec<usize>),
+    Scaffold(usize),
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    UnknownCommand(String),
+    UnknownFlag(String),
+    MissingValue(&'static str),
+    MissingDay,
+    UnexpectedArgument(String),
+    InvalidDay(String),
+    UnknownDay(usize),
+    EmptyRange(String),
+    InvalidPart(String),
+    InvalidFormat(UnknownFormat),
+    ConflictingFlags(&'static str, &'static str),
+    SingleDayInput(usize),
+    InvalidRepeat(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownCommand(command) => write!(f, "unknown command `{command}`"),
+            CliError::UnknownFlag(flag) => write!(f, "unknown flag `{flag}`"),
+            CliError::MissingValue(flag) => write!(f, "flag `{flag}` expects a value"),
+            CliError::MissingDay => write!(f, "a day number is required"),
+            CliError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            CliError::InvalidDay(day) => write!(f, "`{day}` is not a valid day selection"),
+            CliError::UnknownDay(day) => {
+                let available_days = puzzles::available_days()
+                    .map(|day| day.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "day {day} is not solved yet (available days: {available_days})"
+                )
+            }
+            CliError::EmptyRange(range) => write!(f, "range `{range}` does not select any day"),
+            CliError::InvalidPart(part) => write!(f, "part must be 1 or 2, got `{part}`"),
+            CliError::InvalidFormat(error) => write!(f, "{error}"),
+            CliError::ConflictingFlags(first, second) => {
+                write!(f, "flags `{first}` and `{second}` cannot be used together")
+            }
+            CliError::InvalidRepeat(runs) => {
+                write!(f, "repeat count must be a positive integer, got `{runs}`")
+            }
+            CliError::SingleDayInput(count) => write!(
+                f,
+                "a single input file or stdin needs exactly one day, got {count}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+fn parse_number(raw_day: &str) -> Result<usize, CliError> {
+    raw_day
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| CliError::InvalidDay(raw_day.to_string()))
+}
+
+fn check_available(day: usize) -> Result<usize, CliError> {
+    if puzzles::find_day(day).is_some() {
+        Ok(day)
+    } else {
+        Err(CliError::UnknownDay(day))
+    }
+}
+
+fn parse_day(raw_day: &str) -> Result<usize, CliError> {
+    parse_number(raw_day).and_then(check_available)
+}
+
+// Parse a day selection like "4", "1..=3", "1..4", "1,3,5" or "all"
+fn parse_days(selection: &str) -> Result<Vec<usize>, CliError> {
+    if selection == ALL_DAYS_KEYWORD {
+        return Ok(puzzles::available_days().collect());
+    }
+
+    let mut days = Vec::new();
+    for fragment in selection.split(LIST_DELIMITER) {
+        if let Some((start, end)) = fragment.split_once(INCLUSIVE_RANGE_DELIMITER) {
+            let (start, end) = (parse_day(start)?, parse_day(end)?);
+            if start > end {
+                return Err(CliError::EmptyRange(fragment.to_string()));
+            }
+            // Solved days may have gaps, so every day of the range is checked
+            for day in start..=end {
+                days.push(check_available(day)?);
+            }
+        } else if let Some((start, end)) = fragment.split_once(EXCLUSIVE_RANGE_DELIMITER) {
+            // The exclusive end may be one past the last available day
+            let (start, end) = (parse_day(start)?, parse_number(end)?);
+            if start >= end {
+                return Err(CliError::EmptyRange(fragment.to_string()));
+            }
+            for day in start..end {
+                days.push(check_available(day)?);
+            }
+        } else {
+            days.push(parse_day(fragment)?);
+        }
+    }
+    Ok(days)
+}
+
+fn parse_part(raw_part: &str) -> Result<Part, CliError> {
+    match raw_part {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(CliError::InvalidPart(raw_part.to_string())),
+    }
+}
+
+// An empty selection means every available day
+fn select_days(mut days: Vec<usize>) -> Vec<usize> {
+    if days.is_empty() {
+        days.extend(puzzles::available_days());
+    }
+    // Keep the first occurrence of every day so "1,1..=2" runs each day once
+    let mut seen = Vec::with_capacity(days.len());
+    days.retain(|day| {
+        let first = !seen.contains(day);
+        seen.push(*day);
+        first
+    });
+    days
+}
+
+fn parse_examples_days<I: Iterator<Item = String>>(args: I) -> Result<Vec<usize>, CliError> {
+    let mut days = Vec::new();
+    for arg in args {
+        if arg.starts_with('-') {
+            return Err(CliError::UnknownFlag(arg));
+        }
+        days.extend(parse_days(&arg)?);
+    }
+    Ok(select_days(days))
+}
+
+// The day to scaffold is not solved yet, so it is not checked against the registry
+fn parse_scaffold_day<I: Iterator<Item = String>>(mut args: I) -> Result<usize, CliError> {
+    let day = parse_number(&args.next().ok_or(CliError::MissingDay)?)?;
+    match args.next() {
+        Some(arg) => Err(CliError::UnexpectedArgument(arg)),
+        None => Ok(day),
+    }
+}
+
+fn parse_run_options<I: Iterator<Item = String>>(mut args: I) -> Result<RunOptions, CliError> {
+    let mut days = Vec::new();
+    let mut part = None;
+    let mut format = Format::default();
+    let mut input_dir = None;
+    let mut single_input = None;
+    let mut time = false;
+    let mut runs = 1;
+    let mut check = false;
+    let mut answers = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let value = args.next().ok_or(CliError::MissingValue("--part"))?;
+                part = Some(parse_part(&value)?);
+            }
+            "--format" | "-f" => {
+                let value = args.next().ok_or(CliError::MissingValue("--format"))?;
+                format = value.parse().map_err(CliError::InvalidFormat)?;
+            }
+            "--input-dir" | "-d" => {
+                let value = args.next().ok_or(CliError::MissingValue("--input-dir"))?;
+                input_dir = Some(PathBuf::from(value));
+            }
+            "--input" | "-i" => {
+                let value = args.next().ok_or(CliError::MissingValue("--input"))?;
+                single_input = Some(if value == STDIN_PATH {
+                    InputSource::Stdin
+                } else {
+                    InputSource::File(PathBuf::from(value))
+                });
+            }
+            "--stdin" => single_input = Some(InputSource::Stdin),
+            "--time" | "-t" => time = true,
+            "--repeat" | "-r" => {
+                let value = args.next().ok_or(CliError::MissingValue("--repeat"))?;
+                runs = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or(CliError::InvalidRepeat(value))?;
+                time = true;
+            }
+            "--check" | "-c" => check = true,
+            "--answers" | "-a" => {
+                let value = args.next().ok_or(CliError::MissingValue("--answers"))?;
+                answers = Some(PathBuf::from(value));
+            }
+            flag if flag.starts_with('-') => return Err(CliError::UnknownFlag(arg)),
+            selection => days.extend(parse_days(selection)?),
+        }
+    }
+
+    let days = select_days(days);
+
+    let source = match (single_input, input_dir) {
+        (Some(_), Some(_)) => return Err(CliError::ConflictingFlags("--input", "--input-dir")),
+        (Some(_), None) if days.len() != 1 => return Err(CliError::SingleDayInput(days.len())),
+        (Some(single_input), None) => single_input,
+        (None, Some(input_dir)) => InputSource::Directory(input_dir),
+        (None, None) => InputSource::Directory(utils::default_input_dir()),
+    };
+
+    // The answers file lives next to the inputs unless given explicitly
+    let check = match (answers, &source) {
+        (Some(answers), _) => Some(answers),
+        (None, _) if !check => None,
+        (None, InputSource::Directory(input_dir)) => Some(input_dir.join(ANSWERS_FILE_NAME)),
+        (None, _) => Some(utils::default_input_dir().join(ANSWERS_FILE_NAME)),
+    };
+
+    Ok(RunOptions {
+        days,
+        part,
+        format,
+        source,
+        time,
+        runs,
+        check,
+    })
+}
+
+// Parse the command line arguments, excluding the program name
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        None | Some("run") => parse_run_options(args).map(Command::Run),
+        Some("examples") => parse_examples_days(args).map(Command::Examples),
+        Some("scaffold") => parse_scaffold_day(args).map(Command::Scaffold),
+        Some("help" | "--help" | "-h") => Ok(Command::Help),
+        Some(command) => Err(CliError::UnknownCommand(command.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, CliError> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_day_selection() {
+        let run = |days: Vec<usize>, part| {
+            Ok(Command::Run(RunOptions {
+                days,
+                part,
+                format: Format::Pretty,
+                source: InputSource::Directory(utils::default_input_dir()),
+                time: false,
+                runs: 1,
+                check: None,
+            }))
+        };
+        assert_eq!(parse(&[]), run(puzzles::available_days().collect(), None));
+        assert_eq!(
+            parse(&["run", "all"]),
+            run(puzzles::available_days().collect(), None)
+        );
+        assert_eq!(parse(&["run", "4"]), run(vec![4], None));
+        assert_eq!(
+            parse(&["run", "2", "--part", "2"]),
+            run(vec![2], Some(Part::Two))
+        );
+        assert_eq!(parse(&["run", "1..=3"]), run(vec![1, 2, 3], None));
+        assert_eq!(parse(&["run", "2..4"]), run(vec![2, 3], None));
+        assert_eq!(parse(&["run", "5,1,1..=2"]), run(vec![5, 1, 2], None));
+    }
+
+    #[test]
+    fn test_input_sources() {
+        let source = |args: &[&str]| match parse(args) {
+            Ok(Command::Run(options)) => Ok(options.source),
+            Ok(command) => panic!("unexpected command {command:?}"),
+            Err(error) => Err(error),
+        };
+        assert_eq!(
+            source(&["run", "1", "--input-dir", "/tmp/inputs"]),
+            Ok(InputSource::Directory(PathBuf::from("/tmp/inputs")))
+        );
+        assert_eq!(
+            source(&["run", "3", "--input", "day3.txt"]),
+            Ok(InputSource::File(PathBuf::from("day3.txt")))
+        );
+        assert_eq!(source(&["run", "3", "-i", "-"]), Ok(InputSource::Stdin));
+        assert_eq!(source(&["run", "3", "--stdin"]), Ok(InputSource::Stdin));
+        assert_eq!(
+            source(&["run", "1..=2", "--stdin"]),
+            Err(CliError::SingleDayInput(2))
+        );
+        assert_eq!(
+            source(&["run", "1", "--stdin", "-d", "inputs"]),
+            Err(CliError::ConflictingFlags("--input", "--input-dir"))
+        );
+    }
+
+    #[test]
+    fn test_timing_options() {
+        let Ok(Command::Run(options)) = parse(&["run", "4", "--repeat", "10"]) else {
+            panic!("expected a run command");
+        };
+        assert_eq!((options.time, options.runs), (true, 10));
+        let Ok(Command::Run(options)) = parse(&["run", "4", "-t"]) else {
+            panic!("expected a run command");
+        };
+        assert_eq!((options.time, options.runs), (true, 1));
+        assert_eq!(
+            parse(&["run", "4", "--repeat", "0"]),
+            Err(CliError::InvalidRepeat("0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_check_options() {
+        let check = |args: &[&str]| match parse(args) {
+            Ok(Command::Run(options)) => options.check,
+            _ => panic!("expected a run command"),
+        };
+        assert_eq!(check(&["run", "1"]), None);
+        assert_eq!(
+            check(&["run", "1", "--check", "-d", "/tmp/inputs"]),
+            Some(PathBuf::from("/tmp/inputs").join(ANSWERS_FILE_NAME))
+        );
+        assert_eq!(
+            check(&["run", "1", "--answers", "known.toml"]),
+            Some(PathBuf::from("known.toml"))
+        );
+    }
+
+    #[test]
+    fn test_examples_command() {
+        assert_eq!(
+            parse(&["examples"]),
+            Ok(Command::Examples(puzzles::available_days().collect()))
+        );
+        assert_eq!(
+            parse(&["examples", "3", "1..=2"]),
+            Ok(Command::Examples(vec![3, 1, 2]))
+        );
+        assert_eq!(
+            parse(&["examples", "1", "--time"]),
+            Err(CliError::UnknownFlag("--time".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_scaffold_command() {
+        assert_eq!(parse(&["scaffold", "6"]), Ok(Command::Scaffold(6)));
+        assert_eq!(parse(&["scaffold"]), Err(CliError::MissingDay));
+        assert_eq!(
+            parse(&["scaffold", "6", "7"]),
+            Err(CliError::UnexpectedArgument("7".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert_eq!(parse(&["run", "9"]), Err(CliError::UnknownDay(9)));
+        assert_eq!(parse(&["run", "0..=2"]), Err(CliError::UnknownDay(0)));
+        assert_eq!(
+            parse(&["run", "3..=1"]),
+            Err(CliError::EmptyRange("3..=1".to_string()))
+        );
+        assert_eq!(
+            parse(&["run", "four"]),
+            Err(CliError::InvalidDay("four".to_string()))
+        );
+        assert_eq!(
+            parse(&["run", "1", "--part", "3"]),
+            Err(CliError::InvalidPart("3".to_string()))
+        );
+        assert_eq!(
+            parse(&["run", "1", "--part"]),
+            Err(CliError::MissingValue("--part"))
+        );
+        assert_eq!(
+            parse(&["run", "--format", "xml"]),
+            Err(CliError::InvalidFormat(UnknownFormat("xml".to_string())))
+        );
+        assert_eq!(
+            parse(&["solve"]),
+            Err(CliError::UnknownCommand("solve".to_string()))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    MissingFile {
+        path: PathBuf,
+    },
+    UnreadableFile {
+        path: PathBuf,
+        source: io::Error,
+    },
+    InvalidUtf8 {
+        path: PathBuf,
+        valid_up_to: usize,
+    },
+    // Line and column are both 1-based, as shown by editors
+    MalformedLine {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    // Malformed input that cannot be pinned to a line, e.g. a wrong size
+    MalformedInput {
+        message: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn malformed(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::MalformedLine {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingFile { path } => write!(f, "{} does not exist", path.display()),
+            Error::UnreadableFile { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            Error::InvalidUtf8 { path, valid_up_to } => write!(
+                f,
+                "{} is not valid UTF-8 (invalid byte at offset {valid_up_to})",
+                path.display()
+            ),
+            Error::MalformedLine {
+                line,
+                column,
+                message,
+            } => write!(f, "line {line}, column {column}: {message}"),
+            Error::MalformedInput { message } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::UnreadableFile { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{Answers, Verdict};
+use crate::error::{Error, Result};
+use crate::puzzles;
+use crate::runner::{self, DayReport};
+use crate::solution::{DynSolution, Part};
+use crate::utils::{self, InputSource};
+
+const INPUT_EXTENSION: &str = "txt";
+const ANSWERS_EXTENSION: &str = "toml";
+
+// One example input of a day, with its optional sidecar answers file (`<name>.toml`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub day: usize,
+    pub name: String,
+    pub path: PathBuf,
+    pub answers_path: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct ExampleReport {
+    pub example: Example,
+    pub report: DayReport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExampleStatus {
+    // At least one answer is checked and none is wrong
+    Passed,
+    // An answer is wrong or the example could not be solved
+    Failed,
+    // Solved, but with no expected answer to check against
+    Unchecked,
+}
+
+impl ExampleStatus {
+    pub const fn label(self) -> &'static str {
+        match self {
+            ExampleStatus::Passed => "PASS",
+            ExampleStatus::Failed => "FAIL",
+            ExampleStatus::Unchecked => "UNCHECKED",
+        }
+    }
+}
+
+impl ExampleReport {
+    pub fn status(&self) -> ExampleStatus {
+        let checked = self.report.outcome.as_ref().is_ok_and(|solved| {
+            solved
+                .parts
+                .iter()
+                .any(|part| part.verdict == Some(Verdict::Correct))
+        });
+        if !self.report.succeeded() {
+            ExampleStatus::Failed
+        } else if checked {
+            ExampleStatus::Passed
+        } else {
+            ExampleStatus::Unchecked
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.status() == ExampleStatus::Passed
+    }
+}
+
+// List the `*.txt` examples of a day, sorted by name; a day without examples yields none
+pub fn discover(examples_dir: &Path, day: usize) -> Result<Vec<Example>> {
+    let day_dir = utils::day_examples_dir(examples_dir, day);
+    let unreadable = |source| Error::UnreadableFile {
+        path: day_dir.clone(),
+        source,
+    };
+
+    let entries = match fs::read_dir(&day_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(unreadable(error)),
+    };
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(unreadable)?.path();
+        if path
+            .extension()
+            .is_none_or(|extension| extension != INPUT_EXTENSION)
+        {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        examples.push(Example {
+            day,
+            name: name.to_string(),
+            answers_path: path.with_extension(ANSWERS_EXTENSION),
+            path,
+        });
+    }
+    examples.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+// Sidecar answers of the example `name` of a day, in the default examples directory
+pub fn load_answers(day: usize, name: &str) -> Result<Answers> {
+    let path = utils::day_examples_dir(&utils::examples_dir(), day)
+        .join(format!("{name}.{ANSWERS_EXTENSION}"));
+    Answers::parse_for_day(&utils::read_input_file(&path)?, day)
+}
+
+// Solve both parts of an example and check them against its sidecar answers, if any
+pub fn run_example(solution: &dyn DynSolution, example: &Example) -> ExampleReport {
+    let source = InputSource::File(example.path.clone());
+    let mut report = runner::run_day(solution, &source, &Part::ALL, 1);
+
+    let answers = utils::read_input_file(&example.answers_path)
+        .and_then(|raw_answers| Answers::parse_for_day(&raw_answers, example.day));
+    match answers {
+        Ok(answers) => runner::check_reports(std::slice::from_mut(&mut report), &answers),
+        // Without sidecar answers every part is reported as unknown
+        Err(Error::MissingFile { .. }) => {
+            runner::check_reports(std::slice::from_mut(&mut report), &Answers::default())
+        }
+        Err(error) => report.outcome = Err(error),
+    }
+
+    ExampleReport {
+        example: example.clone(),
+        report,
+    }
+}
+
+pub fn run_examples(examples_dir: &Path, days: &[usize]) -> Result<Vec<ExampleReport>> {
+    let mut reports = Vec::new();
+    for &day in days {
+        let Some(solution) = puzzles::find_day(day) else {
+            continue;
+        };
+        for example in discover(examples_dir, day)? {
+            reports.push(run_example(solution, &example));
+        }
+    }
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::report;
+
+    #[test]
+    fn test_all_examples() {
+        let days: Vec<usize> = puzzles::available_days().collect();
+        let reports = run_examples(&utils::examples_dir(), &days).unwrap();
+
+        let mut out = Vec::new();
+        report::render_examples(&reports, &mut out).unwrap();
+        let summary = String::from_utf8(out).unwrap();
+
+        assert!(
+            days.iter()
+                .all(|day| reports.iter().any(|example| example.example.day == *day))
+        );
+        assert!(reports.iter().all(ExampleReport::passed), "{summary}");
+
+        let answers = load_answers(1, "example").unwrap();
+        assert_eq!(answers.expected(1, Part::Two), Some(&6u64.into()));
+        assert!(matches!(
+            load_answers(1, "missing"),
+            Err(Error::MissingFile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_discover_and_check_examples() {
+        let examples_dir = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let day_dir = utils::day_examples_dir(&examples_dir, 1);
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(day_dir.join("b_wrong.txt"), "L68\nL30\n").unwrap();
+        fs::write(day_dir.join("b_wrong.toml"), "part1 = 5\n").unwrap();
+        fs::write(day_dir.join("a_unknown.txt"), "R50\n").unwrap();
+        fs::write(day_dir.join("c_right.txt"), "L68\nL30\n").unwrap();
+        fs::write(day_dir.join("c_right.toml"), "part1 = 0\n").unwrap();
+        fs::write(day_dir.join("notes.md"), "not an example").unwrap();
+
+        let examples = discover(&examples_dir, 1).unwrap();
+        let names: Vec<&str> = examples
+            .iter()
+            .map(|example| example.name.as_str())
+            .collect();
+        assert_eq!(names, ["a_unknown", "b_wrong", "c_right"]);
+        assert!(discover(&examples_dir, 2).unwrap().is_empty());
+
+        let reports = run_examples(&examples_dir, &[1]).unwrap();
+        let statuses: Vec<ExampleStatus> = reports.iter().map(ExampleReport::status).collect();
+        assert_eq!(
+            statuses,
+            [
+                ExampleStatus::Unchecked,
+                ExampleStatus::Failed,
+                ExampleStatus::Passed
+            ]
+        );
+        assert!(!reports[0].passed());
+
+        let mut out = Vec::new();
+        report::render_examples(&reports, &mut out).unwrap();
+        let summary = String::from_utf8(out).unwrap();
+        assert!(summary.starts_with("UNCHECKED  day1/a_unknown.txt"));
+        assert!(summary.contains("\nPASS       day1/c_right.txt "));
+        assert!(summary.ends_with("1 passed, 1 failed, 1 unchecked\n"));
+
+        fs::remove_dir_all(&examples_dir).unwrap();
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+
+use super::{Grid, Neighbors};
+use crate::geometry::Point;
+
+// How a generation is computed from the previous one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Update {
+    // Every cell sees the previous generation, through a second buffer
+    #[default]
+    Synchronous,
+    // Cells are updated in reading order and see the cells already updated in this generation
+    InPlace,
+}
+
+// When a run stops
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Until {
+    // Until a generation changes no cell; never stops on a cycle unless cycles are detected
+    FixedPoint,
+    // After at most that many generations, or earlier on a fixed point
+    Generations(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    FixedPoint,
+    GenerationLimit,
+    // The grid after generation `start + period` is the same as after generation `start`
+    Cycle { start: usize, period: usize },
+}
+
+// Number of cells changed by every generation of a run, and why the run stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub changes: Vec<usize>,
+    pub outcome: Outcome,
+}
+
+impl Run {
+    pub fn generations(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn total_changes(&self) -> usize {
+        self.changes.iter().sum()
+    }
+}
+
+// A cell seen by the rule, with access to its neighbors in the current grid
+#[derive(Debug)]
+pub struct Neighborhood<'a, T> {
+    grid: &'a Grid<T>,
+    row: usize,
+    column: usize,
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    pub const fn row(&self) -> usize {
+        self.row
+    }
+
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn point(&self) -> Point {
+        Point::from((self.row, self.column))
+    }
+
+    pub fn neighbors4(&self) -> Neighbors<'a, T> {
+        self.grid.neighbors4(self.row, self.column)
+    }
+
+    pub fn neighbors8(&self) -> Neighbors<'a, T> {
+        self.grid.neighbors8(self.row, self.column)
+    }
+
+    pub fn diagonal_neighbors(&self) -> Neighbors<'a, T> {
+        self.grid.diagonal_neighbors(self.row, self.column)
+    }
+
+    // Number of the 8 neighbors matching `predicate`
+    pub fn count8(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.neighbors8()
+            .filter(|(_, _, cell)| predicate(cell))
+            .count()
+    }
+}
+
+// Cellular automaton applying `rule` to every cell of a grid, generation after generation
+pub struct Automaton<T, R> {
+    grid: Grid<T>,
+    // Second buffer of synchronous updates, kept between generations
+    next: Vec<T>,
+    rule: R,
+    update: Update,
+    generation: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: FnMut(&T, Neighborhood<'_, T>) -> T,
+{
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        Automaton {
+            grid,
+            next: Vec::new(),
+            rule,
+            update: Update::default(),
+            generation: 0,
+        }
+    }
+
+    pub fn with_update(mut self, update: Update) -> Self {
+        self.update = update;
+        self
+    }
+
+    pub const fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    // Number of generations computed so far
+    pub const fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn apply_rule(&mut self, index: usize) -> T {
+        let neighborhood = Neighborhood {
+            grid: &self.grid,
+            row: index / self.grid.columns,
+            column: index % self.grid.columns,
+        };
+        (self.rule)(&self.grid.data[index], neighborhood)
+    }
+
+    // Compute the next generation, returning the number of changed cells
+    pub fn step(&mut self) -> usize {
+        let mut changes = 0;
+        match self.update {
+            Update::Synchronous => {
+                self.next.clear();
+                for index in 0..self.grid.data.len() {
+                    let cell = self.apply_rule(index);
+                    changes += usize::from(cell != self.grid.data[index]);
+                    self.next.push(cell);
+                }
+                mem::swap(&mut self.grid.data, &mut self.next);
+            }
+            Update::InPlace => {
+                for index in 0..self.grid.data.len() {
+                    let cell = self.apply_rule(index);
+                    if cell != self.grid.data[index] {
+                        self.grid.data[index] = cell;
+                        changes += 1;
+                    }
+                }
+            }
+        }
+        self.generation += 1;
+        changes
+    }
+
+    fn run_with(
+        &mut self,
+        until: Until,
+        mut on_generation: impl FnMut(&Self) -> Option<Outcome>,
+    ) -> Run {
+        let mut changes = Vec::new();
+        let outcome = loop {
+            if let Until::Generations(limit) = until
+                && changes.len() == limit
+            {
+                break Outcome::GenerationLimit;
+            }
+            let changed = self.step();
+            changes.push(changed);
+            if changed == 0 {
+                break Outcome::FixedPoint;
+            }
+            if let Some(outcome) = on_generation(self) {
+                break outcome;
+            }
+        };
+        Run { changes, outcome }
+    }
+
+    pub fn run(&mut self, until: Until) -> Run {
+        self.run_with(until, |_| None)
+    }
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + Eq + Hash,
+    R: FnMut(&T, Neighborhood<'_, T>) -> T,
+{
+    // Like `run`, but also stops when the grid comes back to an earlier state
+    pub fn run_detecting_cycles(&mut self, until: Until) -> Run {
+        let start = self.generation;
+        let mut seen = HashMap::from([(self.grid.clone(), start)]);
+        self.run_with(until, |automaton| {
+            seen.insert(automaton.grid.clone(), automaton.generation)
+                .map(|first| Outcome::Cycle {
+                    start: first - start,
+                    period: automaton.generation - first,
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALIVE: u8 = b'#';
+    const DEAD: u8 = b'.';
+
+    fn life(cell: &u8, neighborhood: Neighborhood<'_, u8>) -> u8 {
+        match (*cell, neighborhood.count8(|&neighbor| neighbor == ALIVE)) {
+            (ALIVE, 2 | 3) | (DEAD, 3) => ALIVE,
+            _ => DEAD,
+        }
+    }
+
+    #[test]
+    fn test_blinker_cycle() {
+        let grid = Grid::<u8>::parse(".....\n..#..\n..#..\n..#..\n.....").unwrap();
+        let mut automaton = Automaton::new(grid.clone(), life);
+        let run = automaton.run_detecting_cycles(Until::FixedPoint);
+        assert_eq!(
+            run.outcome,
+            Outcome::Cycle {
+                start: 0,
+                period: 2
+            }
+        );
+        assert_eq!(run.changes, [4, 4]);
+        assert_eq!(automaton.grid().iter().as_slice(), grid.iter().as_slice());
+
+        let run = automaton.run(Until::Generations(3));
+        assert_eq!(run.outcome, Outcome::GenerationLimit);
+        assert_eq!(automaton.generation(), 5);
+        assert_eq!(automaton.grid().get(2, 1), Some(&ALIVE));
+    }
+
+    #[test]
+    fn test_in_place_updates() {
+        // Every cell copies its left neighbor: in place, the first value spreads in one generation
+        let grid = Grid::<u8>::parse("abcd").unwrap();
+        let shift = |cell: &u8, neighborhood: Neighborhood<'_, u8>| {
+            neighborhood
+                .neighbors4()
+                .find(|&(_, column, _)| column + 1 == neighborhood.column())
+                .map_or(*cell, |(_, _, &left)| left)
+        };
+
+        let mut synchronous = Automaton::new(grid.clone(), shift);
+        assert_eq!(synchronous.step(), 3);
+        assert_eq!(synchronous.grid().iter().as_slice(), b"aabc");
+
+        let mut in_place = Automaton::new(grid, shift).with_update(Update::InPlace);
+        let run = in_place.run(Until::FixedPoint);
+        assert_eq!(in_place.grid().iter().as_slice(), b"aaaa");
+        assert_eq!(
+            (run.changes, run.outcome),
+            (vec![3, 0], Outcome::FixedPoint)
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use super::Grid;
+
+const WORD_BITS: usize = u64::BITS as usize;
+// Enough bit planes to count up to 8 neighbors
+const COUNT_PLANES: usize = 4;
+
+// Boolean grid packing one cell per bit. Every row starts on a new word, so whole rows can be
+// shifted and combined a word (64 cells) at a time; the padding bits of a row are always clear.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    rows: usize,
+    columns: usize,
+    words_per_row: usize,
+}
+
+impl BitGrid {
+    pub fn new(rows: usize, columns: usize) -> Self {
+        let words_per_row = columns.div_ceil(WORD_BITS);
+        BitGrid {
+            words: vec![0; rows * words_per_row],
+            rows,
+            columns,
+            words_per_row,
+        }
+    }
+
+    // Set the cells of `grid` matching `predicate`
+    pub fn from_grid<T, P>(grid: &Grid<T>, mut predicate: P) -> Self
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut bits = BitGrid::new(grid.rows(), grid.columns());
+        for (row, column, cell) in grid.iter_with_coords() {
+            if predicate(cell) {
+                bits.set(row, column, true);
+            }
+        }
+        bits
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        let mut grid = Grid::new(self.rows, self.columns, false);
+        for (row, column) in self.iter_ones() {
+            if let Some(cell) = grid.get_mut(row, column) {
+                *cell = true;
+            }
+        }
+        grid
+    }
+
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    // Word holding a cell, and the mask of the cell within it
+    fn locate(&self, row: usize, column: usize) -> Option<(usize, u64)> {
+        if row >= self.rows || column >= self.columns {
+            return None;
+        }
+        let index = row * self.words_per_row + column / WORD_BITS;
+        Some((index, 1 << (column % WORD_BITS)))
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> bool {
+        self.locate(row, column)
+            .is_some_and(|(index, mask)| self.words[index] & mask != 0)
+    }
+
+    // Returns the previous value, or `None` outside of the grid
+    pub fn set(&mut self, row: usize, column: usize, value: bool) -> Option<bool> {
+        let (index, mask) = self.locate(row, column)?;
+        let previous = self.words[index] & mask != 0;
+        if value {
+            self.words[index] |= mask;
+        } else {
+            self.words[index] &= !mask;
+        }
+        Some(previous)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&word| word != 0)
+    }
+
+    // Coordinates of every set cell, in reading order
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let row = index / self.words_per_row;
+                let first_column = index % self.words_per_row * WORD_BITS;
+                let mut remaining = word;
+                std::iter::from_fn(move || {
+                    let bit = remaining.trailing_zeros() as usize;
+                    (remaining != 0).then(|| {
+                        remaining &= remaining - 1;
+                        (row, first_column + bit)
+                    })
+                })
+            })
+    }
+
+    fn combine(&self, other: &BitGrid, operation: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert_eq!(
+            (self.rows, self.columns),
+            (other.rows, other.columns),
+            "bit grids must have the same size"
+        );
+        BitGrid {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(&word, &other_word)| operation(word, other_word))
+                .collect(),
+            ..*self
+        }
+    }
+
+    pub fn and(&self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |word, other_word| word & other_word)
+    }
+
+    pub fn or(&self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |word, other_word| word | other_word)
+    }
+
+    // Cells set here but not in `other`
+    pub fn and_not(&self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |word, other_word| word & !other_word)
+    }
+
+    // Set cells among the 8 neighbors of a cell, counted a row of 3 bits at a time
+    pub fn neighbor_count(&self, row: usize, column: usize) -> usize {
+        if row >= self.rows || column >= self.columns {
+            return 0;
+        }
+        let first = column.saturating_sub(1);
+        let last = (column + 1).min(self.columns - 1);
+        let window = (row.saturating_sub(1)..=(row + 1).min(self.rows - 1))
+            .map(|row| self.bits(row, first, last - first + 1).count_ones() as usize)
+            .sum::<usize>();
+        window - usize::from(self.get(row, column))
+    }
+
+    // `length` (less than 64) bits of a row starting at `column`
+    fn bits(&self, row: usize, column: usize, length: usize) -> u64 {
+        let row_words = &self.words[row * self.words_per_row..(row + 1) * self.words_per_row];
+        let (index, offset) = (column / WORD_BITS, column % WORD_BITS);
+        let mut bits = row_words[index] >> offset;
+        if offset + length > WORD_BITS {
+            bits |= row_words[index + 1] << (WORD_BITS - offset);
+        }
+        bits & ((1 << length) - 1)
+    }
+
+    // Word `index` of `row`, clear outside of the grid
+    fn word(&self, row: Option<usize>, index: Option<usize>) -> u64 {
+        match (row, index) {
+            (Some(row), Some(index)) if row < self.rows && index < self.words_per_row => {
+                self.words[row * self.words_per_row + index]
+            }
+            _ => 0,
+        }
+    }
+
+    // Cells, set or not, with fewer than `threshold` set neighbors among their 8 neighbors.
+    // Every word of the result adds up the 8 shifted neighbor words of 64 cells at once, into
+    // bit planes holding the binary digits of every count.
+    pub fn fewer_neighbors_than(&self, threshold: usize) -> BitGrid {
+        let mut result = BitGrid::new(self.rows, self.columns);
+        let last_word_mask = match self.columns % WORD_BITS {
+            0 => u64::MAX,
+            used => (1 << used) - 1,
+        };
+
+        for row in 0..self.rows {
+            for index in 0..self.words_per_row {
+                let mut planes = [0u64; COUNT_PLANES];
+                let mut add = |mut carry: u64| {
+                    for plane in &mut planes {
+                        (*plane, carry) = (*plane ^ carry, *plane & carry);
+                    }
+                };
+                for neighbor_row in [row.checked_sub(1), Some(row), row.checked_add(1)] {
+                    let word = self.word(neighbor_row, Some(index));
+                    let previous = self.word(neighbor_row, index.checked_sub(1));
+                    let next = self.word(neighbor_row, index.checked_add(1));
+                    // Left neighbors sit one column lower, right neighbors one column higher
+                    add((word << 1) | (previous >> (WORD_BITS - 1)));
+                    add((word >> 1) | (next << (WORD_BITS - 1)));
+                    if neighbor_row != Some(row) {
+                        add(word);
+                    }
+                }
+
+                // Compare every count with `threshold`, from the most significant plane down
+                let (mut less, mut equal) = (0, u64::MAX);
+                for (bit, plane) in planes.iter().enumerate().rev() {
+                    if threshold >> bit & 1 == 1 {
+                        less |= equal & !plane;
+                        equal &= plane;
+                    } else {
+                        equal &= !plane;
+                    }
+                }
+                if threshold >> COUNT_PLANES != 0 {
+                    less = u64::MAX;
+                }
+
+                let mask = if index + 1 == self.words_per_row {
+                    last_word_mask
+                } else {
+                    u64::MAX
+                };
+                result.words[row * self.words_per_row + index] = less & mask;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bits() {
+        let grid = Grid::<u8>::parse("#..#\n.##.").unwrap();
+        let mut bits = BitGrid::from_grid(&grid, |&cell| cell == b'#');
+        assert_eq!(bits.count_ones(), 4);
+        assert!(bits.get(1, 2));
+        assert!(!bits.get(1, 3));
+        assert_eq!(bits.set(1, 3, true), Some(false));
+        assert_eq!(bits.set(2, 0, true), None);
+        assert_eq!(
+            bits.iter_ones().collect::<Vec<_>>(),
+            [(0, 0), (0, 3), (1, 1), (1, 2), (1, 3)]
+        );
+        assert_eq!(bits.to_grid().to_string(), "#..#\n.###\n");
+        assert_eq!(bits.neighbor_count(0, 2), 4);
+
+        let first_row = BitGrid::from_grid(&grid, |_| true).and_not(&bits);
+        assert_eq!(first_row.count_ones(), 3);
+        assert!(!first_row.and(&bits).any());
+    }
+
+    #[test]
+    fn test_neighbor_counts_match_cell_by_cell() {
+        // Wider than a word, so that shifts carry bits across word boundaries
+        let rows = ["#.##.#", "##..##", ".####."].map(|row| row.repeat(23));
+        let grid = Grid::<u8>::parse(&rows.join("\n")).unwrap();
+        let bits = BitGrid::from_grid(&grid, |&cell| cell == b'#');
+
+        for threshold in 0..=9 {
+            let fewer = bits.fewer_neighbors_than(threshold);
+            for row in 0..grid.rows() {
+                for column in 0..grid.columns() {
+                    let count = grid
+                        .neighbors8(row, column)
+                        .filter(|&(_, _, &cell)| cell == b'#')
+                        .count();
+                    assert_eq!(bits.neighbor_count(row, column), count);
+                    assert_eq!(fewer.get(row, column), count < threshold);
+                }
+            }
+            assert!(fewer.iter_ones().all(|(_, column)| column < grid.columns()));
+        }
+    }
+}
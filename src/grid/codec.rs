@@ -0,0 +1,344 @@
+use super::{Addressing, Glyph, Grid, GridError};
+
+// Text format: a `<rows>x<columns>` header line, ` toroidal` appended for wrapping grids, then
+// every row as exactly `columns` glyphs followed by a newline
+const TOROIDAL_SUFFIX: &str = " toroidal";
+const DIMENSIONS_SEPARATOR: char = 'x';
+const NEWLINE: char = '\n';
+// Binary format: magic, rows and columns as little-endian u64, addressing byte, then the cells
+// in row-major order, `BinaryCell::WIDTH` bytes each
+const MAGIC: &[u8; 4] = b"GRID";
+const DIMENSION_WIDTH: usize = size_of::<u64>();
+const HEADER_WIDTH: usize = MAGIC.len() + 2 * DIMENSION_WIDTH + 1;
+const BOUNDED_BYTE: u8 = 0;
+const TOROIDAL_BYTE: u8 = 1;
+
+// Cells that can be read back from the glyph they print as
+pub trait FromGlyph: Glyph + Sized {
+    fn from_glyph(glyph: char) -> Option<Self>;
+}
+
+impl FromGlyph for u8 {
+    fn from_glyph(glyph: char) -> Option<Self> {
+        u8::try_from(glyph).ok()
+    }
+}
+
+impl FromGlyph for char {
+    fn from_glyph(glyph: char) -> Option<Self> {
+        Some(glyph)
+    }
+}
+
+impl FromGlyph for bool {
+    fn from_glyph(glyph: char) -> Option<Self> {
+        [true, false].into_iter().find(|cell| cell.glyph() == glyph)
+    }
+}
+
+// Cells stored as a fixed number of bytes
+pub trait BinaryCell: Sized {
+    const WIDTH: usize;
+
+    fn encode(&self, bytes: &mut Vec<u8>);
+
+    // `bytes` holds exactly `WIDTH` bytes
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_binary_cell_for_integers {
+    ($($integer:ty),*) => {$(
+        impl BinaryCell for $integer {
+            const WIDTH: usize = size_of::<$integer>();
+
+            fn encode(&self, bytes: &mut Vec<u8>) {
+                bytes.extend(self.to_le_bytes());
+            }
+
+            fn decode(bytes: &[u8]) -> Option<Self> {
+                bytes.try_into().ok().map(<$integer>::from_le_bytes)
+            }
+        }
+    )*};
+}
+
+impl_binary_cell_for_integers!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl BinaryCell for bool {
+    const WIDTH: usize = 1;
+
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        bytes.push(u8::from(*self));
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl BinaryCell for char {
+    const WIDTH: usize = u32::WIDTH;
+
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        u32::from(*self).encode(bytes);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        u32::decode(bytes).and_then(char::from_u32)
+    }
+}
+
+fn invalid_header(header: &str) -> GridError {
+    GridError::InvalidHeader(format!("expected `<rows>x<columns>`, got `{header}`"))
+}
+
+impl<T: Glyph> Grid<T> {
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}{DIMENSIONS_SEPARATOR}{}", self.rows, self.columns);
+        if self.addressing == Addressing::Toroidal {
+            text.push_str(TOROIDAL_SUFFIX);
+        }
+        text.push(NEWLINE);
+        for row in self.data.chunks_exact(self.columns.max(1)) {
+            text.extend(row.iter().map(Glyph::glyph));
+            text.push(NEWLINE);
+        }
+        // Rows without columns hold no cells to chunk
+        if self.columns == 0 {
+            text.extend(std::iter::repeat_n(NEWLINE, self.rows));
+        }
+        text
+    }
+}
+
+impl<T: FromGlyph> Grid<T> {
+    // Read back a grid written by `to_text`; rows are counted in glyphs, so even newline cells
+    // round-trip
+    pub fn from_text(text: &str) -> Result<Self, GridError> {
+        let (header, body) = text
+            .split_once(NEWLINE)
+            .ok_or_else(|| invalid_header(text))?;
+        let (dimensions, addressing) = match header.strip_suffix(TOROIDAL_SUFFIX) {
+            Some(dimensions) => (dimensions, Addressing::Toroidal),
+            None => (header, Addressing::Bounded),
+        };
+        let (rows, columns) = dimensions
+            .split_once(DIMENSIONS_SEPARATOR)
+            .and_then(|(rows, columns)| Some((rows.parse().ok()?, columns.parse().ok()?)))
+            .ok_or_else(|| invalid_header(header))?;
+
+        // The header is line 1
+        let ragged_row = |row: usize| GridError::RaggedRow {
+            line: row + 2,
+            length: body.lines().nth(row).map_or(0, |line| line.chars().count()),
+            expected: columns,
+        };
+        let mut glyphs = body.chars();
+        let mut data = Vec::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let glyph = glyphs.next().ok_or_else(|| ragged_row(row))?;
+                let cell = T::from_glyph(glyph).ok_or(GridError::UnknownGlyph {
+                    line: row + 2,
+                    column: column + 1,
+                    glyph,
+                })?;
+                data.push(cell);
+            }
+            if glyphs.next() != Some(NEWLINE) {
+                return Err(ragged_row(row));
+            }
+        }
+        let extra_rows = glyphs.as_str().lines().count();
+        if extra_rows > 0 {
+            return Err(GridError::RowCount {
+                expected: rows,
+                found: rows + extra_rows,
+            });
+        }
+
+        Ok(Grid {
+            data,
+            rows,
+            columns,
+            addressing,
+        })
+    }
+}
+
+impl<T: BinaryCell> Grid<T> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_WIDTH + self.data.len() * T::WIDTH);
+        bytes.extend(MAGIC);
+        bytes.extend((self.rows as u64).to_le_bytes());
+        bytes.extend((self.columns as u64).to_le_bytes());
+        bytes.push(match self.addressing {
+            Addressing::Bounded => BOUNDED_BYTE,
+            Addressing::Toroidal => TOROIDAL_BYTE,
+        });
+        for cell in &self.data {
+            cell.encode(&mut bytes);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, GridError> {
+        let Some((header, cells)) = bytes.split_at_checked(HEADER_WIDTH) else {
+            return Err(GridError::ByteLength {
+                expected: HEADER_WIDTH,
+                found: bytes.len(),
+            });
+        };
+        let (magic, header) = header.split_at(MAGIC.len());
+        if magic != MAGIC {
+            return Err(GridError::InvalidHeader(format!(
+                "expected the {MAGIC:?} magic, got {magic:?}"
+            )));
+        }
+        let (rows, header) = header.split_at(DIMENSION_WIDTH);
+        let (columns, addressing) = header.split_at(DIMENSION_WIDTH);
+        let dimension = |bytes: &[u8]| {
+            bytes
+                .try_into()
+                .ok()
+                .map(u64::from_le_bytes)
+                .and_then(|dimension| usize::try_from(dimension).ok())
+                .ok_or_else(|| {
+                    GridError::InvalidHeader(format!("dimension {bytes:?} is too large"))
+                })
+        };
+        let (rows, columns) = (dimension(rows)?, dimension(columns)?);
+        let addressing = match addressing {
+            [BOUNDED_BYTE] => Addressing::Bounded,
+            [TOROIDAL_BYTE] => Addressing::Toroidal,
+            _ => {
+                return Err(GridError::InvalidHeader(format!(
+                    "unknown addressing {addressing:?}"
+                )));
+            }
+        };
+
+        let expected = rows
+            .checked_mul(columns)
+            .and_then(|cells| cells.checked_mul(T::WIDTH))
+            .and_then(|width| width.checked_add(HEADER_WIDTH))
+            .ok_or_else(|| GridError::InvalidHeader(format!("{rows}x{columns} is too large")))?;
+        if bytes.len() != expected {
+            return Err(GridError::ByteLength {
+                expected,
+                found: bytes.len(),
+            });
+        }
+        let data = (0..rows * columns)
+            .map(|index| {
+                let start = index * T::WIDTH;
+                T::decode(&cells[start..start + T::WIDTH]).ok_or(GridError::InvalidCell { index })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Grid {
+            data,
+            rows,
+            columns,
+            addressing,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_text_round_trip() {
+        let rows = vec![b"a b".to_vec(), b"\n\r\xff".to_vec(), b"#..".to_vec()];
+        let grid = Grid::try_from(rows).unwrap();
+        let text = grid.to_text();
+        assert_eq!(text, "3x3\na b\n\n\r\u{ff}\n#..\n");
+        assert_eq!(Grid::<u8>::from_text(&text), Ok(grid));
+
+        let toroidal = Grid::new(2, 0, true).with_addressing(Addressing::Toroidal);
+        assert_eq!(toroidal.to_text(), "2x0 toroidal\n\n\n");
+        assert_eq!(Grid::from_text(&toroidal.to_text()), Ok(toroidal));
+        assert_eq!(
+            Grid::<char>::from_text(&Grid::new(0, 4, 'x').to_text()).map(|grid| grid.columns()),
+            Ok(4)
+        );
+    }
+
+    #[test]
+    fn test_malformed_text() {
+        assert!(matches!(
+            Grid::<u8>::from_text("2by2\nab\ncd\n"),
+            Err(GridError::InvalidHeader(_))
+        ));
+        assert_eq!(
+            Grid::<u8>::from_text("2x2\nab\nc\n"),
+            Err(GridError::RaggedRow {
+                line: 3,
+                length: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            Grid::<bool>::from_text("1x2\n#?\n"),
+            Err(GridError::UnknownGlyph {
+                line: 2,
+                column: 2,
+                glyph: '?'
+            })
+        );
+        assert_eq!(
+            Grid::<u8>::from_text("1x2\nab\ncd\n"),
+            Err(GridError::RowCount {
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let grid = Grid::try_from(vec![vec![-1i32, 2, i32::MAX], vec![0, 7, i32::MIN]])
+            .unwrap()
+            .with_addressing(Addressing::Toroidal);
+        let bytes = grid.to_bytes();
+        assert_eq!(bytes.len(), HEADER_WIDTH + 6 * 4);
+        assert_eq!(Grid::<i32>::from_bytes(&bytes), Ok(grid));
+
+        let flags = Grid::parse_with(".#\n#.", |byte| byte == b'#').unwrap();
+        assert_eq!(Grid::from_bytes(&flags.to_bytes()), Ok(flags.clone()));
+        let glyphs = flags.map(|&flag| if flag { 'é' } else { '.' });
+        assert_eq!(Grid::from_bytes(&glyphs.to_bytes()), Ok(glyphs));
+
+        assert!(matches!(
+            Grid::<i32>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(GridError::ByteLength { .. })
+        ));
+        assert!(matches!(
+            Grid::<u8>::from_bytes(b"GRIT"),
+            Err(GridError::ByteLength { .. })
+        ));
+        let mut invalid = flags.to_bytes();
+        invalid[HEADER_WIDTH + 1] = 2;
+        assert_eq!(
+            Grid::<bool>::from_bytes(&invalid),
+            Err(GridError::InvalidCell { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_grids_as_keys() {
+        let grid = Grid::<u8>::parse("ab\ncd").unwrap();
+        let seen = HashSet::from([grid.clone(), grid.rotate_180().rotate_180()]);
+        assert_eq!(seen.len(), 1);
+        assert_ne!(grid, grid.transpose());
+        assert_ne!(grid, grid.clone().with_addressing(Addressing::Toroidal));
+    }
+}
@@ -0,0 +1,446 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{Colour, Grid};
+
+const PPM_MAGIC: &str = "P6";
+const MAX_CHANNEL: u8 = u8::MAX;
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const PNG_BIT_DEPTH: u8 = 8;
+const PNG_TRUECOLOUR: u8 = 2;
+// Compression, filter and interlace methods of the IHDR chunk
+const PNG_DEFAULT_METHOD: u8 = 0;
+const PNG_NO_FILTER: u8 = 0;
+// Dimensions are stored in 4 bytes but limited to 2^31 - 1, and must not be 0
+const PNG_MAX_DIMENSION: u32 = i32::MAX as u32;
+// Chunk lengths are limited to 2^31 - 1 as well, so image data is split across IDAT chunks
+const IDAT_CHUNK_LENGTH: usize = 1 << 20;
+// Deflate with a 32K window and the fastest (here: no) compression
+const ZLIB_HEADER: [u8; 2] = [0x78, 0x01];
+const STORED_BLOCK_LIMIT: usize = u16::MAX as usize;
+const ADLER_MODULUS: u32 = 65521;
+const CRC_POLYNOMIAL: u32 = 0xEDB8_8320;
+const CRC_TABLE: [u32; 256] = crc_table();
+const CHANNELS: usize = 3;
+const FRAME_PREFIX: &str = "frame_";
+const FRAME_DIGITS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(MAX_CHANNEL, MAX_CHANNEL, MAX_CHANNEL);
+
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Rgb { red, green, blue }
+    }
+}
+
+// Same palette as the terminal highlights
+impl From<Colour> for Rgb {
+    fn from(colour: Colour) -> Self {
+        match colour {
+            Colour::Red => Rgb::new(0xe0, 0x40, 0x40),
+            Colour::Green => Rgb::new(0x40, 0xc0, 0x40),
+            Colour::Yellow => Rgb::new(0xe0, 0xc0, 0x30),
+            Colour::Blue => Rgb::new(0x40, 0x70, 0xe0),
+            Colour::Magenta => Rgb::new(0xc0, 0x40, 0xc0),
+            Colour::Cyan => Rgb::new(0x30, 0xc0, 0xc0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageFormat {
+    // Raw PPM, trivial to write but large
+    #[default]
+    Ppm,
+    // PNG with uncompressed deflate blocks, readable by any viewer
+    Png,
+}
+
+impl ImageFormat {
+    pub const fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+// Picture of a grid, one `scale` x `scale` square of pixels per cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    rows: usize,
+    columns: usize,
+    scale: usize,
+    // One colour per cell, in row-major order
+    colours: Vec<Rgb>,
+}
+
+impl<T> Grid<T> {
+    // One pixel per cell, coloured by `colour`
+    pub fn to_image<F>(&self, colour: F) -> Image
+    where
+        F: FnMut(&T) -> Rgb,
+    {
+        Image {
+            rows: self.rows,
+            columns: self.columns,
+            scale: 1,
+            colours: self.data.iter().map(colour).collect(),
+        }
+    }
+}
+
+impl Image {
+    // Draw every cell as a `scale` x `scale` square, at least one pixel wide
+    pub fn scaled(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    // Size in pixels, `None` when it overflows
+    pub const fn width(&self) -> Option<usize> {
+        self.columns.checked_mul(self.scale)
+    }
+
+    pub const fn height(&self) -> Option<usize> {
+        self.rows.checked_mul(self.scale)
+    }
+
+    fn too_large(&self, format: ImageFormat) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "a {}x{} grid at scale {} cannot be a {} image",
+                self.rows,
+                self.columns,
+                self.scale,
+                format.extension().to_uppercase()
+            ),
+        )
+    }
+
+    // Every row of pixels as RGB bytes, from top to bottom
+    fn scanlines(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        self.colours
+            .chunks_exact(self.columns.max(1))
+            .flat_map(move |row| {
+                let scanline: Vec<u8> = row
+                    .iter()
+                    .flat_map(|colour| [colour.red, colour.green, colour.blue].repeat(self.scale))
+                    .collect();
+                std::iter::repeat_n(scanline, self.scale)
+            })
+    }
+
+    // Fails on images whose size in bytes overflows
+    pub fn to_ppm(&self) -> io::Result<Vec<u8>> {
+        let (Some(width), Some(height)) = (self.width(), self.height()) else {
+            return Err(self.too_large(ImageFormat::Ppm));
+        };
+        let length = width
+            .checked_mul(height)
+            .and_then(|pixels| pixels.checked_mul(CHANNELS))
+            .ok_or_else(|| self.too_large(ImageFormat::Ppm))?;
+        let mut ppm = format!("{PPM_MAGIC}\n{width} {height}\n{MAX_CHANNEL}\n").into_bytes();
+        ppm.reserve(length);
+        self.scanlines().for_each(|scanline| ppm.extend(scanline));
+        Ok(ppm)
+    }
+
+    // Fails on images without pixels or too large for PNG, rather than writing a corrupt file
+    pub fn to_png(&self) -> io::Result<Vec<u8>> {
+        let dimension = |pixels: Option<usize>| {
+            pixels
+                .and_then(|pixels| u32::try_from(pixels).ok())
+                .filter(|pixels| (1..=PNG_MAX_DIMENSION).contains(pixels))
+        };
+        let (Some(width), Some(height)) = (dimension(self.width()), dimension(self.height()))
+        else {
+            return Err(self.too_large(ImageFormat::Png));
+        };
+        // A filter byte starts every scanline
+        let length = (width as usize)
+            .checked_mul(CHANNELS)
+            .and_then(|scanline| scanline.checked_add(1))
+            .and_then(|scanline| scanline.checked_mul(height as usize))
+            .ok_or_else(|| self.too_large(ImageFormat::Png))?;
+
+        let mut header = Vec::new();
+        header.extend(width.to_be_bytes());
+        header.extend(height.to_be_bytes());
+        header.extend([
+            PNG_BIT_DEPTH,
+            PNG_TRUECOLOUR,
+            PNG_DEFAULT_METHOD,
+            PNG_DEFAULT_METHOD,
+            PNG_DEFAULT_METHOD,
+        ]);
+
+        let mut pixels = Vec::with_capacity(length);
+        for scanline in self.scanlines() {
+            pixels.push(PNG_NO_FILTER);
+            pixels.extend(scanline);
+        }
+
+        let mut png = PNG_SIGNATURE.to_vec();
+        push_chunk(&mut png, b"IHDR", &header);
+        for data in zlib_stored(&pixels).chunks(IDAT_CHUNK_LENGTH) {
+            push_chunk(&mut png, b"IDAT", data);
+        }
+        push_chunk(&mut png, b"IEND", &[]);
+        Ok(png)
+    }
+
+    pub fn encode(&self, format: ImageFormat) -> io::Result<Vec<u8>> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Png => self.to_png(),
+        }
+    }
+
+    pub fn save(&self, path: &Path, format: ImageFormat) -> io::Result<()> {
+        fs::write(path, self.encode(format)?)
+    }
+}
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut byte = 0;
+    while byte < table.len() {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < u8::BITS {
+            crc = if crc & 1 == 1 {
+                CRC_POLYNOMIAL ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    !bytes.into_iter().fold(u32::MAX, |crc, &byte| {
+        CRC_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (low, high) = bytes.iter().fold((1, 0), |(low, high), &byte| {
+        let low = (low + u32::from(byte)) % ADLER_MODULUS;
+        (low, (high + low) % ADLER_MODULUS)
+    });
+    (high << 16) | low
+}
+
+// Length, type, data and CRC of the type and data; `data` must fit in a chunk
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend(kind);
+    png.extend(data);
+    png.extend(crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+// Zlib stream of uncompressed deflate blocks, so that no compressor is needed
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = ZLIB_HEADER.to_vec();
+    let blocks: Vec<&[u8]> = if data.is_empty() {
+        vec![&[]]
+    } else {
+        data.chunks(STORED_BLOCK_LIMIT).collect()
+    };
+    let last = blocks.len() - 1;
+    for (index, block) in blocks.into_iter().enumerate() {
+        let length = block.len() as u16;
+        stream.push(u8::from(index == last));
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+// Numbered images written to a directory, to be assembled into an animation, e.g. with
+// `ffmpeg -i frame_%05d.png`
+#[derive(Debug)]
+pub struct FrameWriter {
+    directory: PathBuf,
+    format: ImageFormat,
+    frames: usize,
+}
+
+impl FrameWriter {
+    pub fn create(directory: impl Into<PathBuf>, format: ImageFormat) -> io::Result<Self> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+        Ok(FrameWriter {
+            directory,
+            format,
+            frames: 0,
+        })
+    }
+
+    // Write the next frame and return its path
+    pub fn write(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.directory.join(format!(
+            "{FRAME_PREFIX}{:0FRAME_DIGITS$}.{}",
+            self.frames,
+            self.format.extension()
+        ));
+        image.save(&path, self.format)?;
+        self.frames += 1;
+        Ok(path)
+    }
+
+    pub const fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn flags() -> Image {
+        let grid = Grid::parse_with("#.", |byte| byte == b'#').unwrap();
+        grid.to_image(|&flag| if flag { Rgb::WHITE } else { Colour::Red.into() })
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = flags().scaled(2);
+        assert_eq!((image.width(), image.height()), (Some(4), Some(2)));
+        let white = [MAX_CHANNEL; 3];
+        let red = [0xe0, 0x40, 0x40];
+        let row = [white, white, red, red].concat();
+        let expected = [b"P6\n4 2\n255\n".to_vec(), row.clone(), row].concat();
+        assert_eq!(image.to_ppm().unwrap(), expected);
+
+        let huge = Grid::new(2, 1, true)
+            .to_image(|_| Rgb::BLACK)
+            .scaled(usize::MAX);
+        assert_eq!(huge.height(), None);
+        let error = huge.to_ppm().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        let wide = Grid::new(1, 1, true)
+            .to_image(|_| Rgb::BLACK)
+            .scaled(1 << 32);
+        assert!(wide.to_ppm().is_err());
+    }
+
+    // Type and data of every chunk, checking their CRC
+    fn png_chunks(png: &[u8]) -> Vec<(Vec<u8>, Vec<u8>)> {
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (kind, data) = (&rest[4..8], &rest[8..8 + length]);
+            let crc = u32::from_be_bytes(rest[8 + length..12 + length].try_into().unwrap());
+            assert_eq!(crc, crc32(kind.iter().chain(data)));
+            chunks.push((kind.to_vec(), data.to_vec()));
+            rest = &rest[12 + length..];
+        }
+        chunks
+    }
+
+    #[test]
+    fn test_png() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+
+        let image = flags().scaled(2);
+        let chunks = png_chunks(&image.to_png().unwrap());
+        let kinds: Vec<&[u8]> = chunks.iter().map(|(kind, _)| &kind[..]).collect();
+        assert_eq!(kinds, [b"IHDR", b"IDAT", b"IEND"]);
+        assert_eq!(chunks[0].1, [0, 0, 0, 4, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+
+        // A single stored block holding both filtered scanlines
+        let pixels: Vec<u8> = image
+            .scanlines()
+            .flat_map(|scanline| [vec![PNG_NO_FILTER], scanline].concat())
+            .collect();
+        let idat = &chunks[1].1;
+        assert_eq!(idat[..3], [0x78, 0x01, 1]);
+        assert_eq!(idat[3..5], (pixels.len() as u16).to_le_bytes());
+        assert_eq!(idat[7..idat.len() - 4], pixels);
+        assert_eq!(idat[idat.len() - 4..], adler32(&pixels).to_be_bytes());
+
+        let error = Grid::new(2, 0, true)
+            .to_image(|_| Rgb::BLACK)
+            .to_png()
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        let tall = Grid::new(1, 1, true).to_image(|_| Rgb::BLACK);
+        assert!(tall.clone().scaled(1 << 31).to_png().is_err());
+        assert!(tall.scaled(usize::MAX).to_png().is_err());
+    }
+
+    #[test]
+    fn test_png_data_split_across_chunks() {
+        // 600 rows of 1 + 600 * 3 bytes make a zlib stream longer than one IDAT chunk
+        let image = Grid::new(1, 1, true).to_image(|_| Rgb::WHITE).scaled(600);
+        let chunks = png_chunks(&image.to_png().unwrap());
+        let kinds: Vec<&[u8]> = chunks.iter().map(|(kind, _)| &kind[..]).collect();
+        assert_eq!(kinds, [b"IHDR", b"IDAT", b"IDAT", b"IEND"]);
+        assert_eq!(chunks[1].1.len(), IDAT_CHUNK_LENGTH);
+
+        let pixels = [vec![PNG_NO_FILTER], vec![MAX_CHANNEL; 600 * CHANNELS]]
+            .concat()
+            .repeat(600);
+        assert_eq!(
+            [&chunks[1].1[..], &chunks[2].1].concat(),
+            zlib_stored(&pixels)
+        );
+    }
+
+    #[test]
+    fn test_stored_blocks() {
+        let data = vec![7; STORED_BLOCK_LIMIT + 1];
+        let stream = zlib_stored(&data);
+        assert_eq!(stream.len(), 2 + 2 * 5 + data.len() + 4);
+        assert_eq!(stream[2], 0);
+        assert_eq!(stream[2 + 5 + STORED_BLOCK_LIMIT], 1);
+        assert_eq!(
+            zlib_stored(&[]),
+            [0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_frame_writer() {
+        let directory = env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut frames = FrameWriter::create(&directory, ImageFormat::Ppm).unwrap();
+        let image = flags();
+        let first = frames.write(&image).unwrap();
+        let second = frames.write(&image.clone().scaled(3)).unwrap();
+
+        assert_eq!(frames.frames(), 2);
+        assert_eq!(first, directory.join("frame_00000.ppm"));
+        assert_eq!(second, directory.join("frame_00001.ppm"));
+        assert_eq!(
+            fs::read(&second).unwrap(),
+            image.clone().scaled(3).to_ppm().unwrap()
+        );
+        let error = frames.write(&image.scaled(usize::MAX)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(frames.frames(), 2);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
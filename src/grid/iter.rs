@@ -0,0 +1,150 @@
+use std::iter::StepBy;
+use std::slice;
+
+use super::Grid;
+
+impl<T> Grid<T> {
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    // Cells in reading order as `(row, column, &cell)`
+    pub fn iter_with_coords(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let columns = self.columns;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (index / columns, index % columns, cell))
+    }
+
+    pub fn iter_with_coords_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let columns = self.columns;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, cell)| (index / columns, index % columns, cell))
+    }
+
+    // Every row as a slice, from top to bottom
+    pub fn rows_iter(&self) -> slice::ChunksExact<'_, T> {
+        // An empty grid has no cells to split, whatever its width
+        self.data.chunks_exact(self.columns.max(1))
+    }
+
+    pub fn rows_iter_mut(&mut self) -> slice::ChunksExactMut<'_, T> {
+        self.data.chunks_exact_mut(self.columns.max(1))
+    }
+
+    // Every column as an iterator over its cells, from left to right
+    pub fn columns_iter(&self) -> impl Iterator<Item = StepBy<slice::Iter<'_, T>>> {
+        // Grids without rows have columns but no cells
+        (0..self.columns).map(|column| {
+            let cells = self.data.get(column..).unwrap_or_default();
+            cells.iter().step_by(self.columns)
+        })
+    }
+
+    pub fn column_mut(&mut self, column: usize) -> Option<StepBy<slice::IterMut<'_, T>>> {
+        let columns = self.columns;
+        let cells = self.data.get_mut(column..).unwrap_or_default();
+        (column < columns).then(|| cells.iter_mut().step_by(columns))
+    }
+
+    // Every column as the cells it holds, from left to right; columns are not contiguous, so the
+    // mutable references are gathered up front
+    pub fn columns_iter_mut(&mut self) -> impl Iterator<Item = Vec<&mut T>> {
+        let mut columns: Vec<Vec<&mut T>> = (0..self.columns)
+            .map(|_| Vec::with_capacity(self.rows))
+            .collect();
+        for (index, cell) in self.data.iter_mut().enumerate() {
+            columns[index % self.columns].push(cell);
+        }
+        columns.into_iter()
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    // Coordinates of every cell equal to `value`, in reading order
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter_with_coords()
+            .filter(move |(_, _, cell)| *cell == value)
+            .map(|(row, column, _)| (row, column))
+    }
+
+    // Coordinates of the first cell equal to `value`, in reading order
+    pub fn find(&self, value: &T) -> Option<(usize, usize)> {
+        self.positions_of(value).next()
+    }
+
+    // The first cell equal to `value`, to be replaced in place
+    pub fn find_mut(&mut self, value: &T) -> Option<(usize, usize, &mut T)> {
+        self.iter_with_coords_mut()
+            .find(|(_, _, cell)| **cell == *value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iterators_with_coords() {
+        let mut grid = Grid::<u8>::parse("a.b\n.a.").unwrap();
+        let cells: Vec<_> = grid
+            .iter_with_coords()
+            .filter(|(_, _, cell)| **cell != b'.')
+            .map(|(row, column, &cell)| (row, column, cell))
+            .collect();
+        assert_eq!(cells, [(0, 0, b'a'), (0, 2, b'b'), (1, 1, b'a')]);
+
+        assert_eq!(
+            grid.positions_of(&b'a').collect::<Vec<_>>(),
+            [(0, 0), (1, 1)]
+        );
+        assert_eq!(grid.find(&b'b'), Some((0, 2)));
+        assert_eq!(grid.find(&b'z'), None);
+
+        let (row, column, cell) = grid.find_mut(&b'b').unwrap();
+        *cell = b'c';
+        assert_eq!((row, column), (0, 2));
+        for (row, column, cell) in grid.iter_with_coords_mut() {
+            if row == column {
+                *cell = b'x';
+            }
+        }
+        assert_eq!(grid.to_string(), "x.c\n.x.\n");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::<u8>::parse("abc\ndef").unwrap();
+        assert_eq!(grid.rows_iter().collect::<Vec<_>>(), [b"abc", b"def"]);
+        let columns: Vec<Vec<u8>> = grid
+            .columns_iter()
+            .map(|column| column.copied().collect())
+            .collect();
+        assert_eq!(columns, [b"ad", b"be", b"cf"]);
+
+        grid.rows_iter_mut().next().unwrap().reverse();
+        for cell in grid.column_mut(1).unwrap() {
+            *cell = b'-';
+        }
+        for mut column in grid.columns_iter_mut().skip(2) {
+            *column[0] = b'!';
+        }
+        assert_eq!(grid.to_string(), "c-!\nd-f\n");
+        assert!(grid.iter_mut().all(|cell| cell.is_ascii_graphic()));
+        assert_eq!(Grid::<u8>::new(0, 0, b'.').rows_iter().count(), 0);
+
+        let mut flat = Grid::new(0, 3, b'.');
+        assert_eq!(flat.rows_iter().count(), 0);
+        assert!(
+            flat.columns_iter()
+                .all(|mut column| column.next().is_none())
+        );
+        assert_eq!(flat.columns_iter().count(), 3);
+        assert_eq!(flat.column_mut(2).map(Iterator::count), Some(0));
+        assert!(flat.column_mut(3).is_none());
+        assert!(flat.columns_iter_mut().all(|column| column.is_empty()));
+    }
+}
@@ -1,39 +1,334 @@
 use std::convert::TryFrom;
-use std::ops::Not;
+use std::fmt;
+use std::ops::{Index, IndexMut, Not};
+
+use crate::error::Error;
+use crate::geometry::{Direction8, Point};
+
+mod automaton;
+mod bits;
+mod codec;
+mod image;
+mod iter;
+mod render;
+mod search;
+mod sparse;
+mod transform;
+
+pub use automaton::{Automaton, Neighborhood, Outcome, Run, Until, Update};
+pub use bits::BitGrid;
+pub use codec::{BinaryCell, FromGlyph};
+pub use image::{FrameWriter, Image, ImageFormat, Rgb};
+pub use render::{Colour, Glyph, Render};
+pub use search::{Components, Connectivity, Path, ShortestPaths};
+pub use sparse::SparseGrid;
+pub use transform::SubGrid;
 
 const DEFAULT_COLUMN_VALUE: usize = 0;
-const DIRECTIONS: [[isize; 2]; 8] = [
-    [-1, -1],
-    [-1, 0],
-    [-1, 1],
-    [0, -1],
-    [0, 1],
-    [1, -1],
-    [1, 0],
-    [1, 1],
+const ORTHOGONAL_DIRECTIONS: [Direction8; 4] = [
+    Direction8::Up,
+    Direction8::Left,
+    Direction8::Right,
+    Direction8::Down,
+];
+const DIAGONAL_DIRECTIONS: [Direction8; 4] = [
+    Direction8::UpLeft,
+    Direction8::UpRight,
+    Direction8::DownLeft,
+    Direction8::DownRight,
+];
+const DIRECTIONS: [Direction8; 8] = [
+    Direction8::UpLeft,
+    Direction8::Up,
+    Direction8::UpRight,
+    Direction8::Left,
+    Direction8::Right,
+    Direction8::DownLeft,
+    Direction8::Down,
+    Direction8::DownRight,
 ];
 
-// 2D row-major grid struct
+// Cells addressed by `Point`, shared by the dense `Grid` and the unbounded `SparseGrid`
+pub trait Cells {
+    type Cell;
+
+    // Where `point` lands once the addressing mode is applied, with the cell stored there
+    fn locate(&self, point: Point) -> Option<(Point, &Self::Cell)>;
+
+    // Every stored cell with its position
+    fn cells(&self) -> impl Iterator<Item = (Point, &Self::Cell)>;
+
+    fn cell(&self, point: Point) -> Option<&Self::Cell> {
+        self.locate(point).map(|(_, cell)| cell)
+    }
+
+    // Up, left, right and down neighbors
+    fn neighbors4_at(&self, point: Point) -> PointNeighbors<'_, Self>
+    where
+        Self: Sized,
+    {
+        PointNeighbors::new(self, point, &ORTHOGONAL_DIRECTIONS)
+    }
+
+    // Orthogonal and diagonal neighbors, in reading order
+    fn neighbors8_at(&self, point: Point) -> PointNeighbors<'_, Self>
+    where
+        Self: Sized,
+    {
+        PointNeighbors::new(self, point, &DIRECTIONS)
+    }
+
+    fn diagonal_neighbors_at(&self, point: Point) -> PointNeighbors<'_, Self>
+    where
+        Self: Sized,
+    {
+        PointNeighbors::new(self, point, &DIAGONAL_DIRECTIONS)
+    }
+}
+
+// Existing neighbors of a point as `(position, &cell)`, walking a fixed set of directions
+#[derive(Debug, Clone)]
+pub struct PointNeighbors<'a, G> {
+    grid: &'a G,
+    center: Point,
+    directions: std::slice::Iter<'static, Direction8>,
+}
+
+impl<'a, G> PointNeighbors<'a, G> {
+    fn new(grid: &'a G, center: Point, directions: &'static [Direction8]) -> Self {
+        PointNeighbors {
+            grid,
+            center,
+            directions: directions.iter(),
+        }
+    }
+}
+
+impl<'a, G: Cells> Iterator for PointNeighbors<'a, G> {
+    type Item = (Point, &'a G::Cell);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (grid, center) = (self.grid, self.center);
+        self.directions
+            .by_ref()
+            .find_map(|&direction| grid.locate(center.step(direction)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.directions.len()))
+    }
+}
+
+// Neighbors of a cell as `(row, column, &cell)`
 #[derive(Debug, Clone)]
+pub struct Neighbors<'a, T>(PointNeighbors<'a, Grid<T>>);
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Located points are always inside the grid
+        self.0
+            .next()
+            .map(|(point, cell)| (point.row as usize, point.column as usize, cell))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+// How coordinates outside of the grid are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Addressing {
+    // Coordinates outside of the grid have no cell
+    #[default]
+    Bounded,
+    // Coordinates wrap around the edges, so every cell has a full set of neighbors
+    Toroidal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    // `line` is 1-based; for grids built from rows, it is the row index plus one
+    RaggedRow {
+        line: usize,
+        length: usize,
+        expected: usize,
+    },
+    // Serialised grids
+    InvalidHeader(String),
+    UnknownGlyph {
+        line: usize,
+        column: usize,
+        glyph: char,
+    },
+    RowCount {
+        expected: usize,
+        found: usize,
+    },
+    ByteLength {
+        expected: usize,
+        found: usize,
+    },
+    // `index` is the position of the cell in row-major order
+    InvalidCell {
+        index: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                line,
+                length,
+                expected,
+            } => write!(f, "line {line} has {length} cells, expected {expected}"),
+            GridError::InvalidHeader(message) => write!(f, "invalid grid header: {message}"),
+            GridError::UnknownGlyph {
+                line,
+                column,
+                glyph,
+            } => write!(f, "line {line}, column {column}: unknown cell {glyph:?}"),
+            GridError::RowCount { expected, found } => {
+                write!(f, "grid has {found} rows, expected {expected}")
+            }
+            GridError::ByteLength { expected, found } => {
+                write!(f, "grid has {found} bytes, expected {expected}")
+            }
+            GridError::InvalidCell { index } => write!(f, "cell {index} has an invalid value"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl From<GridError> for Error {
+    fn from(error: GridError) -> Self {
+        match error {
+            // Point at the first missing or extra cell
+            GridError::RaggedRow {
+                line,
+                length,
+                expected,
+            } => Error::malformed(
+                line,
+                length.min(expected) + 1,
+                format!("row has {length} cells, expected {expected}"),
+            ),
+            GridError::UnknownGlyph {
+                line,
+                column,
+                glyph,
+            } => Error::malformed(line, column, format!("unknown cell {glyph:?}")),
+            // Not tied to a position in the text
+            other => Error::MalformedInput {
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+// Rows of a grid, one per line whatever its content; blank lines ending the input are not rows
+fn grid_lines(raw_data: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = raw_data.lines().collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+// 2D row-major grid struct; grids are equal when they have the same shape, cells and addressing
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     data: Vec<T>,
     rows: usize,
     columns: usize,
+    addressing: Addressing,
 }
 
 impl<T> Grid<T> {
-    pub fn iter(&self) -> std::slice::Iter<'_, T> {
-        self.data.iter()
+    // Map every character of a block of lines to a cell, spaces included; every line must be as
+    // long as the first one
+    pub fn parse_with<F>(raw_data: &str, mut cell: F) -> Result<Self, GridError>
+    where
+        F: FnMut(u8) -> T,
+    {
+        let lines = grid_lines(raw_data);
+        let columns = lines
+            .first()
+            .map_or(DEFAULT_COLUMN_VALUE, |line| line.len());
+        let mut data = Vec::with_capacity(lines.len() * columns);
+        for (index, line) in lines.iter().enumerate() {
+            if line.len() != columns {
+                return Err(GridError::RaggedRow {
+                    line: index + 1,
+                    length: line.len(),
+                    expected: columns,
+                });
+            }
+            data.extend(line.bytes().map(&mut cell));
+        }
+
+        Ok(Grid {
+            data,
+            rows: lines.len(),
+            columns,
+            addressing: Addressing::Bounded,
+        })
+    }
+
+    // Like `parse_with`, but lines shorter than the longest one are padded with `fill`
+    pub fn parse_padded_with<F>(raw_data: &str, fill: T, mut cell: F) -> Self
+    where
+        T: Clone,
+        F: FnMut(u8) -> T,
+    {
+        let lines = grid_lines(raw_data);
+        let columns = lines
+            .iter()
+            .map(|line| line.len())
+            .max()
+            .unwrap_or(DEFAULT_COLUMN_VALUE);
+        let mut data = Vec::with_capacity(lines.len() * columns);
+        for line in &lines {
+            data.extend(line.bytes().map(&mut cell));
+            data.extend(std::iter::repeat_n(fill.clone(), columns - line.len()));
+        }
+
+        Grid {
+            data,
+            rows: lines.len(),
+            columns,
+            addressing: Addressing::Bounded,
+        }
+    }
+
+    pub fn with_addressing(mut self, addressing: Addressing) -> Self {
+        self.addressing = addressing;
+        self
+    }
+
+    pub const fn addressing(&self) -> Addressing {
+        self.addressing
+    }
+
+    // Same-shaped grid with every cell transformed by `f`
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            rows: self.rows,
+            columns: self.columns,
+            addressing: self.addressing,
+        }
     }
-}
 
-impl Grid<u8> {
-    pub fn parse(raw_data: &str) -> Result<Self, &'static str> {
-        raw_data
-            .split_whitespace()
-            .map(|row| row.as_bytes().to_vec())
-            .collect::<Vec<_>>()
-            .try_into()
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
     }
 
     pub const fn rows(&self) -> usize {
@@ -52,39 +347,148 @@ impl Grid<u8> {
         rows * self.columns + columns
     }
 
-    pub fn get(&self, rows: usize, columns: usize) -> Option<&u8> {
+    // Index of the cell at `(rows, columns)` once the addressing mode is applied
+    fn resolve_index(&self, rows: usize, columns: usize) -> Option<usize> {
+        let (rows, columns) = match self.addressing {
+            Addressing::Bounded => (rows, columns),
+            Addressing::Toroidal => (
+                rows.checked_rem(self.rows)?,
+                columns.checked_rem(self.columns)?,
+            ),
+        };
         if self.is_valid_coord(rows, columns).not() {
             return None;
         }
-        self.data.get(self.get_index(rows, columns))
+        Some(self.get_index(rows, columns))
     }
 
-    pub fn get_mut(&mut self, rows: usize, columns: usize) -> Option<&mut u8> {
-        if self.is_valid_coord(rows, columns).not() {
-            return None;
-        }
-        let index = self.get_index(rows, columns);
+    pub fn get(&self, rows: usize, columns: usize) -> Option<&T> {
+        let index = self.resolve_index(rows, columns)?;
+        self.data.get(index)
+    }
+
+    pub fn get_mut(&mut self, rows: usize, columns: usize) -> Option<&mut T> {
+        let index = self.resolve_index(rows, columns)?;
         self.data.get_mut(index)
     }
 
-    pub fn find_neighbors(&self, rows: usize, columns: usize) -> Vec<u8> {
-        DIRECTIONS
-            .into_iter()
-            .filter_map(|[dr, dc]| {
-                if let (Some(new_rows), Some(new_columns)) =
-                    (rows.checked_add_signed(dr), columns.checked_add_signed(dc))
-                {
-                    self.get(new_rows, new_columns).copied()
-                } else {
-                    None
-                }
-            })
+    // `(row, column)` of the cell a point addresses
+    pub fn coords_of(&self, point: Point) -> Option<(usize, usize)> {
+        let (rows, columns) = match self.addressing {
+            Addressing::Bounded => (
+                usize::try_from(point.row).ok()?,
+                usize::try_from(point.column).ok()?,
+            ),
+            Addressing::Toroidal if self.data.is_empty() => return None,
+            Addressing::Toroidal => (
+                point.row.rem_euclid(self.rows as isize) as usize,
+                point.column.rem_euclid(self.columns as isize) as usize,
+            ),
+        };
+        self.is_valid_coord(rows, columns)
+            .then_some((rows, columns))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.coords_of(point).is_some()
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        let (rows, columns) = self.coords_of(point)?;
+        self.get(rows, columns)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        let (rows, columns) = self.coords_of(point)?;
+        self.get_mut(rows, columns)
+    }
+
+    // Up, left, right and down neighbors
+    pub fn neighbors4(&self, row: usize, column: usize) -> Neighbors<'_, T> {
+        Neighbors(self.neighbors4_at(Point::from((row, column))))
+    }
+
+    // Orthogonal and diagonal neighbors, in reading order
+    pub fn neighbors8(&self, row: usize, column: usize) -> Neighbors<'_, T> {
+        Neighbors(self.neighbors8_at(Point::from((row, column))))
+    }
+
+    pub fn diagonal_neighbors(&self, row: usize, column: usize) -> Neighbors<'_, T> {
+        Neighbors(self.diagonal_neighbors_at(Point::from((row, column))))
+    }
+}
+
+impl<T> Cells for Grid<T> {
+    type Cell = T;
+
+    fn locate(&self, point: Point) -> Option<(Point, &T)> {
+        let (rows, columns) = self.coords_of(point)?;
+        let cell = self.get(rows, columns)?;
+        Some((Point::from((rows, columns)), cell))
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (Point, &T)> {
+        let columns = self.columns;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (Point::from((index / columns, index % columns)), cell))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // A `rows` x `columns` grid with every cell set to `value`
+    pub fn new(rows: usize, columns: usize, value: T) -> Self {
+        Grid {
+            data: vec![value; rows * columns],
+            rows,
+            columns,
+            addressing: Addressing::Bounded,
+        }
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    pub fn find_neighbors(&self, rows: usize, columns: usize) -> Vec<T> {
+        self.neighbors8(rows, columns)
+            .map(|(_, _, &cell)| cell)
             .collect()
     }
 }
 
+impl<T: From<u8>> Grid<T> {
+    pub fn parse(raw_data: &str) -> Result<Self, GridError> {
+        Self::parse_with(raw_data, T::from)
+    }
+
+    pub fn parse_padded(raw_data: &str, fill: u8) -> Self
+    where
+        T: Clone,
+    {
+        Self::parse_padded_with(raw_data, T::from(fill), T::from)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        let (rows, columns) = (self.rows, self.columns);
+        self.get_point(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the {rows}x{columns} grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (rows, columns) = (self.rows, self.columns);
+        self.get_point_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the {rows}x{columns} grid"))
+    }
+}
+
 impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
-    type Error = &'static str;
+    type Error = GridError;
 
     fn try_from(two_directional_array: Vec<Vec<T>>) -> Result<Self, Self::Error> {
         let rows = two_directional_array.len();
@@ -92,8 +496,15 @@ impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
             .first()
             .map_or(DEFAULT_COLUMN_VALUE, |r| r.len());
 
-        if !two_directional_array.iter().all(|r| r.len() == columns) {
-            return Err("All rows must have the same length");
+        if let Some(index) = two_directional_array
+            .iter()
+            .position(|r| r.len() != columns)
+        {
+            return Err(GridError::RaggedRow {
+                line: index + 1,
+                length: two_directional_array[index].len(),
+                expected: columns,
+            });
         }
 
         let data = two_directional_array.into_iter().flatten().collect();
@@ -102,6 +513,181 @@ impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
             data,
             rows,
             columns,
+            addressing: Addressing::Bounded,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Empty,
+        Wall,
+    }
+
+    #[test]
+    fn test_parse_with_domain_type() {
+        let grid = Grid::parse_with("#.\n.#\n..", |byte| match byte {
+            b'#' => Cell::Wall,
+            _ => Cell::Empty,
+        })
+        .unwrap();
+        assert_eq!((grid.rows(), grid.columns()), (3, 2));
+        assert_eq!(grid.get(1, 1), Some(&Cell::Wall));
+        assert_eq!(grid.get(2, 0), Some(&Cell::Empty));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(
+            grid.find_neighbors(0, 0),
+            [Cell::Empty, Cell::Empty, Cell::Wall]
+        );
+        assert!(Grid::parse_with("##\n#", |byte| byte == b'#').is_err());
+    }
+
+    #[test]
+    fn test_parse_preserves_lines() {
+        let grid = Grid::<u8>::parse("a b\r\n c \r\n").unwrap();
+        assert_eq!((grid.rows(), grid.columns()), (2, 3));
+        assert_eq!(grid.get(0, 1), Some(&b' '));
+        assert_eq!(grid.get(1, 1), Some(&b'c'));
+        assert_eq!(Grid::<u8>::parse("ab\ncd\n\n\n").unwrap().rows(), 2);
+        assert_eq!(Grid::<u8>::parse("").unwrap().rows(), 0);
+
+        let error = Grid::<u8>::parse("ab\n\ncd").unwrap_err();
+        assert_eq!(
+            error,
+            GridError::RaggedRow {
+                line: 2,
+                length: 0,
+                expected: 2
+            }
+        );
+        assert_eq!(error.to_string(), "line 2 has 0 cells, expected 2");
+        assert!(matches!(
+            Grid::<u8>::try_from(vec![vec![1, 2], vec![3]]),
+            Err(GridError::RaggedRow { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_grid_errors_as_input_errors() {
+        let error = Error::from(GridError::UnknownGlyph {
+            line: 3,
+            column: 2,
+            glyph: '?',
+        });
+        assert_eq!(error.to_string(), "line 3, column 2: unknown cell '?'");
+
+        let error = Error::from(GridError::RowCount {
+            expected: 1,
+            found: 2,
+        });
+        assert!(matches!(error, Error::MalformedInput { .. }));
+        assert_eq!(
+            error.to_string(),
+            GridError::RowCount {
+                expected: 1,
+                found: 2
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::<u8>::parse_padded("ab\n\nabc\r\na", b'.');
+        assert_eq!(grid.to_string(), "ab.\n...\nabc\na..\n");
+        let walls = Grid::parse_padded_with("#\n.#", Cell::Wall, |byte| match byte {
+            b'#' => Cell::Wall,
+            _ => Cell::Empty,
+        });
+        assert_eq!(walls.get(0, 1), Some(&Cell::Wall));
+        assert_eq!(walls.get(1, 0), Some(&Cell::Empty));
+    }
+
+    #[test]
+    fn test_neighbor_coordinates() {
+        let grid = Grid::<u8>::parse("abc\ndef\nghi").unwrap();
+        let coords = |neighbors: Neighbors<'_, u8>| {
+            neighbors
+                .map(|(row, column, &cell)| (row, column, cell))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            coords(grid.neighbors4(0, 1)),
+            [(0, 0, b'a'), (0, 2, b'c'), (1, 1, b'e')]
+        );
+        assert_eq!(
+            coords(grid.diagonal_neighbors(1, 1)),
+            [(0, 0, b'a'), (0, 2, b'c'), (2, 0, b'g'), (2, 2, b'i')]
+        );
+        assert_eq!(
+            coords(grid.neighbors8(2, 2)),
+            [(1, 1, b'e'), (1, 2, b'f'), (2, 1, b'h')]
+        );
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors4(5, 5).count(), 0);
+    }
+
+    #[test]
+    fn test_point_accessors() {
+        let mut grid = Grid::<u8>::parse("ab\ncd").unwrap();
+        let mut point = Point::new(1, 0);
+        assert_eq!(grid[point], b'c');
+        point = point.step(Direction::Right);
+        grid[point] = b'x';
+        assert_eq!(grid.get(1, 1), Some(&b'x'));
+        assert_eq!(grid.coords_of(point), Some((1, 1)));
+        assert!(!grid.contains(point.step(Direction::Down)));
+        assert_eq!(grid.get_point(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the 2x2 grid")]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::<u8>::parse("ab\ncd").unwrap();
+        let _ = grid[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn test_toroidal_addressing() {
+        let grid = Grid::<u8>::parse("abc\ndef\nghi")
+            .unwrap()
+            .with_addressing(Addressing::Toroidal);
+        assert_eq!(grid.get(3, 4), Some(&b'b'));
+        assert_eq!(grid[Point::new(-1, -1)], b'i');
+        assert_eq!(grid.coords_of(Point::new(-4, 5)), Some((2, 2)));
+        let neighbors: Vec<_> = grid
+            .neighbors4(0, 0)
+            .map(|(row, column, &cell)| (row, column, cell))
+            .collect();
+        assert_eq!(
+            neighbors,
+            [(2, 0, b'g'), (0, 2, b'c'), (0, 1, b'b'), (1, 0, b'd')]
+        );
+        assert_eq!(grid.neighbors8(0, 0).count(), 8);
+
+        let cells: Vec<_> = grid.cells().map(|(point, _)| point).take(4).collect();
+        assert_eq!(cells, [(0, 0), (0, 1), (0, 2), (1, 0)].map(Point::from));
+        assert_eq!(
+            Grid::new(0, 0, b'.')
+                .with_addressing(Addressing::Toroidal)
+                .get(0, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_generic_cells() {
+        let mut distances = Grid::new(2, 3, None::<u32>);
+        *distances.get_mut(1, 2).unwrap() = Some(4);
+        assert_eq!(distances.get(1, 2), Some(&Some(4)));
+        assert_eq!(distances.iter().flatten().count(), 1);
+
+        let digits = Grid::<u32>::parse("12\n34").unwrap();
+        assert_eq!(digits.get(1, 0), Some(&u32::from(b'3')));
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt;
+
+use super::Grid;
+use crate::geometry::Point;
+
+const ANSI_RESET: &str = "\x1b[0m";
+const RULER_STEP: usize = 10;
+const TRUE_GLYPH: char = '#';
+const FALSE_GLYPH: char = '.';
+
+// Cells that print as a single character
+pub trait Glyph {
+    fn glyph(&self) -> char;
+}
+
+impl Glyph for u8 {
+    fn glyph(&self) -> char {
+        char::from(*self)
+    }
+}
+
+impl Glyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+impl Glyph for bool {
+    fn glyph(&self) -> char {
+        if *self { TRUE_GLYPH } else { FALSE_GLYPH }
+    }
+}
+
+impl<T: Glyph> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    // Bold foreground colour, so highlights stand out from plain cells
+    const fn ansi_code(self) -> &'static str {
+        match self {
+            Colour::Red => "\x1b[1;31m",
+            Colour::Green => "\x1b[1;32m",
+            Colour::Yellow => "\x1b[1;33m",
+            Colour::Blue => "\x1b[1;34m",
+            Colour::Magenta => "\x1b[1;35m",
+            Colour::Cyan => "\x1b[1;36m",
+        }
+    }
+}
+
+// Printable view of a grid, with coloured cells and optional row and column rulers
+#[derive(Debug, Clone)]
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    // One optional colour per cell, in row-major order; empty until something is highlighted
+    colours: Vec<Option<Colour>>,
+    rulers: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn render(&self) -> Render<'_, T> {
+        Render {
+            grid: self,
+            colours: Vec::new(),
+            rulers: false,
+        }
+    }
+}
+
+impl<T> Render<'_, T> {
+    // Colour the given cells; later highlights win over earlier ones, points outside are ignored,
+    // even on toroidal grids
+    pub fn highlight<P, I>(mut self, points: I, colour: Colour) -> Self
+    where
+        P: Into<Point>,
+        I: IntoIterator<Item = P>,
+    {
+        if self.colours.is_empty() {
+            self.colours = vec![None; self.grid.data.len()];
+        }
+        for point in points {
+            let point = point.into();
+            let (Ok(row), Ok(column)) = (usize::try_from(point.row), usize::try_from(point.column))
+            else {
+                continue;
+            };
+            if self.grid.is_valid_coord(row, column) {
+                self.colours[self.grid.get_index(row, column)] = Some(colour);
+            }
+        }
+        self
+    }
+
+    // Number rows on the left and columns on top
+    pub fn with_rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    fn write_column_rulers(&self, f: &mut fmt::Formatter<'_>, margin: usize) -> fmt::Result {
+        let columns = self.grid.columns;
+        if columns > RULER_STEP {
+            write!(f, "{:margin$}", "")?;
+            for column in 0..columns {
+                if column % RULER_STEP == 0 {
+                    write!(f, "{}", column / RULER_STEP % RULER_STEP)?;
+                } else {
+                    write!(f, " ")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        write!(f, "{:margin$}", "")?;
+        for column in 0..columns {
+            write!(f, "{}", column % RULER_STEP)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl<T: Glyph> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label_width = self.grid.rows.saturating_sub(1).to_string().len();
+        if self.rulers {
+            self.write_column_rulers(f, label_width + 1)?;
+        }
+
+        for row in 0..self.grid.rows {
+            if self.rulers {
+                write!(f, "{row:>label_width$} ")?;
+            }
+            for column in 0..self.grid.columns {
+                let index = self.grid.get_index(row, column);
+                let glyph = self.grid.data[index].glyph();
+                match self.colours.get(index).copied().flatten() {
+                    Some(colour) => write!(f, "{}{glyph}{ANSI_RESET}", colour.ansi_code())?,
+                    None => write!(f, "{glyph}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Addressing;
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::<u8>::parse("..@\n@@.").unwrap();
+        assert_eq!(grid.to_string(), "..@\n@@.\n");
+        let flags = Grid::parse_with("#.", |byte| byte == b'#').unwrap();
+        assert_eq!(flags.to_string(), "#.\n");
+    }
+
+    #[test]
+    fn test_highlights_and_rulers() {
+        let grid = Grid::<u8>::parse("..@\n@@.").unwrap();
+        let rendered = grid
+            .render()
+            .highlight([(0, 2), (5, 5)], Colour::Red)
+            .highlight([Point::new(0, 2), Point::new(1, 0)], Colour::Green)
+            .to_string();
+        assert_eq!(rendered, "..\x1b[1;32m@\x1b[0m\n\x1b[1;32m@\x1b[0m@.\n");
+
+        let toroidal = grid.clone().with_addressing(Addressing::Toroidal);
+        let rendered = toroidal
+            .render()
+            .highlight([Point::new(5, 5), Point::new(-1, 0)], Colour::Red)
+            .to_string();
+        assert_eq!(rendered, "..@\n@@.\n");
+
+        let wide = Grid::new(11, 12, b'.');
+        let rendered = wide.render().with_rulers().to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "   0         1 ");
+        assert_eq!(lines[1], "   012345678901");
+        assert_eq!(lines[2], " 0 ............");
+        assert_eq!(lines[12], "10 ............");
+    }
+}
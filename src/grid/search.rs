@@ -0,0 +1,320 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::{Cells, Grid, PointNeighbors};
+use crate::geometry::Point;
+
+// Which neighbors a search may move to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Connectivity {
+    // Up, left, right and down
+    #[default]
+    Four,
+    // Orthogonal and diagonal
+    Eight,
+}
+
+// Distances from a start cell, with the previous cell of a shortest path to every reached cell
+#[derive(Debug, Clone)]
+pub struct ShortestPaths {
+    pub start: Point,
+    pub distances: Grid<Option<u32>>,
+    previous: Grid<Option<Point>>,
+}
+
+impl ShortestPaths {
+    fn unreached<T>(grid: &Grid<T>, start: Point) -> Self {
+        ShortestPaths {
+            start,
+            distances: Grid::new(grid.rows, grid.columns, None),
+            previous: Grid::new(grid.rows, grid.columns, None),
+        }
+    }
+
+    pub fn distance(&self, goal: Point) -> Option<u32> {
+        self.distances.get_point(goal).copied().flatten()
+    }
+
+    // Cells from the start to `goal`, both included
+    pub fn path_to(&self, goal: Point) -> Option<Vec<Point>> {
+        self.distance(goal)?;
+        let mut path = vec![goal];
+        let mut current = goal;
+        while let Some(previous) = self.previous.get_point(current).copied().flatten() {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub cost: u32,
+    // From the start to the goal, both included
+    pub points: Vec<Point>,
+}
+
+// Connected regions, labelled from 0 in reading order of their first cell
+#[derive(Debug, Clone)]
+pub struct Components {
+    pub labels: Grid<usize>,
+    // Number of cells of every component, by label
+    pub sizes: Vec<usize>,
+}
+
+impl Components {
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+}
+
+impl<T> Grid<T> {
+    fn moves(&self, point: Point, connectivity: Connectivity) -> PointNeighbors<'_, Self> {
+        match connectivity {
+            Connectivity::Four => self.neighbors4_at(point),
+            Connectivity::Eight => self.neighbors8_at(point),
+        }
+    }
+
+    // Breadth-first search, moving between cells for which `passable(from, to)` holds
+    pub fn bfs<P>(&self, start: Point, connectivity: Connectivity, mut passable: P) -> ShortestPaths
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        let Some((start, _)) = self.locate(start) else {
+            return ShortestPaths::unreached(self, start);
+        };
+        let mut paths = ShortestPaths::unreached(self, start);
+        paths.distances[start] = Some(0);
+
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((point, distance)) = queue.pop_front() {
+            let cell = &self[point];
+            for (next, next_cell) in self.moves(point, connectivity) {
+                if paths.distances[next].is_some() || !passable(cell, next_cell) {
+                    continue;
+                }
+                paths.distances[next] = Some(distance + 1);
+                paths.previous[next] = Some(point);
+                queue.push_back((next, distance + 1));
+            }
+        }
+        paths
+    }
+
+    // Best-first search shared by Dijkstra (no goal, null heuristic) and A*
+    fn best_first<C, H>(
+        &self,
+        start: Point,
+        goal: Option<Point>,
+        connectivity: Connectivity,
+        mut cost: C,
+        mut heuristic: H,
+    ) -> ShortestPaths
+    where
+        C: FnMut(&T, &T) -> Option<u32>,
+        H: FnMut(Point) -> u32,
+    {
+        let Some((start, _)) = self.locate(start) else {
+            return ShortestPaths::unreached(self, start);
+        };
+        let mut paths = ShortestPaths::unreached(self, start);
+        paths.distances[start] = Some(0);
+
+        let mut heap = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+        while let Some(Reverse((_, distance, point))) = heap.pop() {
+            // Skip stale entries of cells reached again with a shorter distance
+            if paths.distances[point].is_some_and(|best| best < distance) {
+                continue;
+            }
+            if goal == Some(point) {
+                break;
+            }
+            let cell = &self[point];
+            for (next, next_cell) in self.moves(point, connectivity) {
+                let Some(step) = cost(cell, next_cell) else {
+                    continue;
+                };
+                let next_distance = distance + step;
+                if paths.distances[next].is_none_or(|best| next_distance < best) {
+                    paths.distances[next] = Some(next_distance);
+                    paths.previous[next] = Some(point);
+                    heap.push(Reverse((
+                        next_distance + heuristic(next),
+                        next_distance,
+                        next,
+                    )));
+                }
+            }
+        }
+        paths
+    }
+
+    // Shortest paths where moving from a cell to a neighbor costs `cost(from, to)`, `None` when
+    // the move is not allowed
+    pub fn dijkstra<C>(&self, start: Point, connectivity: Connectivity, cost: C) -> ShortestPaths
+    where
+        C: FnMut(&T, &T) -> Option<u32>,
+    {
+        self.best_first(start, None, connectivity, cost, |_| 0)
+    }
+
+    // Shortest path to `goal`, guided by a heuristic that never overestimates the remaining cost
+    pub fn a_star<C, H>(
+        &self,
+        start: Point,
+        goal: Point,
+        connectivity: Connectivity,
+        cost: C,
+        heuristic: H,
+    ) -> Option<Path>
+    where
+        C: FnMut(&T, &T) -> Option<u32>,
+        H: FnMut(Point) -> u32,
+    {
+        let (goal, _) = self.locate(goal)?;
+        let paths = self.best_first(start, Some(goal), connectivity, cost, heuristic);
+        Some(Path {
+            cost: paths.distance(goal)?,
+            points: paths.path_to(goal)?,
+        })
+    }
+
+    // Every cell reachable from `start` through cells for which `connected(from, to)` holds, in
+    // reading order
+    pub fn flood_fill<F>(
+        &self,
+        start: Point,
+        connectivity: Connectivity,
+        connected: F,
+    ) -> Vec<Point>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.bfs(start, connectivity, connected)
+            .distances
+            .cells()
+            .filter(|(_, distance)| distance.is_some())
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    // Label the regions of cells for which `connected(from, to)` holds; `connected` should be
+    // symmetric
+    pub fn components<F>(&self, connectivity: Connectivity, mut connected: F) -> Components
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut labels: Grid<Option<usize>> = Grid::new(self.rows, self.columns, None);
+        let mut sizes = Vec::new();
+
+        for (start, _) in self.cells() {
+            if labels[start].is_some() {
+                continue;
+            }
+            let label = sizes.len();
+            labels[start] = Some(label);
+            let mut size = 0;
+            let mut queue = VecDeque::from([start]);
+            while let Some(point) = queue.pop_front() {
+                size += 1;
+                let cell = &self[point];
+                for (next, next_cell) in self.moves(point, connectivity) {
+                    if labels[next].is_none() && connected(cell, next_cell) {
+                        labels[next] = Some(label);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+
+        Components {
+            labels: labels.map(|label| label.unwrap_or_default()),
+            sizes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALL: u8 = b'#';
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+";
+
+    fn open(from: &u8, to: &u8) -> bool {
+        *from != WALL && *to != WALL
+    }
+
+    #[test]
+    fn test_bfs_distances_and_path() {
+        let maze = Grid::<u8>::parse(MAZE).unwrap();
+        let paths = maze.bfs(Point::ORIGIN, Connectivity::Four, open);
+        assert_eq!(paths.distance(Point::new(3, 6)), Some(11));
+        assert_eq!(paths.distance(Point::new(0, 3)), Some(15));
+        assert_eq!(paths.distance(Point::new(0, 2)), None);
+
+        let path = paths.path_to(Point::new(2, 2)).unwrap();
+        assert_eq!(
+            path,
+            [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)].map(Point::from)
+        );
+        assert!(paths.path_to(Point::new(1, 1)).is_none());
+
+        let diagonal = maze.bfs(Point::ORIGIN, Connectivity::Eight, open);
+        assert_eq!(diagonal.distance(Point::new(3, 6)), Some(7));
+    }
+
+    #[test]
+    fn test_weighted_searches() {
+        let costs = Grid::<u8>::parse("1163\n1381\n2136").unwrap();
+        let cost = |_: &u8, to: &u8| Some(u32::from(to - b'0'));
+        let goal = Point::new(2, 3);
+
+        let paths = costs.dijkstra(Point::ORIGIN, Connectivity::Four, cost);
+        assert_eq!(paths.distance(goal), Some(13));
+        assert_eq!(paths.distances.get(0, 0), Some(&Some(0)));
+
+        let path = costs
+            .a_star(Point::ORIGIN, goal, Connectivity::Four, cost, |point| {
+                point.manhattan_distance(goal) as u32
+            })
+            .unwrap();
+        assert_eq!(path.cost, 13);
+        assert_eq!(path.points.first(), Some(&Point::ORIGIN));
+        assert_eq!(path.points.last(), Some(&goal));
+        assert_eq!(path.points.len(), 6);
+
+        let walled = costs.a_star(
+            Point::ORIGIN,
+            goal,
+            Connectivity::Four,
+            |_, to| (*to != b'8' && *to != b'3').then_some(1),
+            |_| 0,
+        );
+        assert_eq!(walled, None);
+    }
+
+    #[test]
+    fn test_flood_fill_and_components() {
+        let maze = Grid::<u8>::parse(MAZE).unwrap();
+        let region = maze.flood_fill(Point::new(0, 3), Connectivity::Four, |from, to| from == to);
+        assert_eq!(region.len(), 20);
+        assert!(region.contains(&Point::new(3, 6)));
+        assert!(!region.contains(&Point::new(0, 2)));
+
+        let components = maze.components(Connectivity::Four, |from, to| from == to);
+        assert_eq!(components.count(), 6);
+        assert_eq!(components.sizes, [20, 3, 2, 1, 1, 1]);
+        assert_eq!(components.labels.get(0, 2), Some(&1));
+        assert_eq!(components.labels.get(3, 0), Some(&0));
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use super::Cells;
+use crate::geometry::Point;
+
+// Unbounded grid storing only its occupied cells, at any signed coordinate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Keep the characters mapped to `Some` cell, with the first character at the origin
+    pub fn parse_with<F>(raw_data: &str, mut cell: F) -> Self
+    where
+        F: FnMut(u8) -> Option<T>,
+    {
+        raw_data
+            .lines()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.bytes()
+                    .enumerate()
+                    .map(move |(column, byte)| (Point::from((row, column)), byte))
+            })
+            .filter_map(|(point, byte)| cell(byte).map(|cell| (point, cell)))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    // Returns the previous cell at `point`, if any
+    pub fn insert(&mut self, point: Point, cell: T) -> Option<T> {
+        self.cells.insert(point, cell)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    // Smallest and largest corners of the rectangle holding every cell
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.cells.keys().fold(None, |bounds, &point| {
+            let (min, max) = bounds.unwrap_or((point, point));
+            Some((
+                Point::new(min.row.min(point.row), min.column.min(point.column)),
+                Point::new(max.row.max(point.row), max.column.max(point.column)),
+            ))
+        })
+    }
+}
+
+impl<T> Cells for SparseGrid<T> {
+    type Cell = T;
+
+    fn locate(&self, point: Point) -> Option<(Point, &T)> {
+        self.cells.get(&point).map(|cell| (point, cell))
+    }
+
+    // In no particular order
+    fn cells(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, cell)| (point, cell))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> Self {
+        SparseGrid {
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::parse_with("#.\n.#", |byte| (byte == b'#').then_some(byte));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Point::new(0, 0), Point::new(1, 1))));
+
+        let far = Point::new(-5, -3);
+        grid.insert(far, b'@');
+        assert_eq!(grid.cell(far), Some(&b'@'));
+        assert_eq!(grid.bounds(), Some((far, Point::new(1, 1))));
+
+        let neighbors: Vec<Point> = grid
+            .neighbors8_at(Point::new(1, 0))
+            .map(|(point, _)| point)
+            .collect();
+        assert_eq!(neighbors, [Point::new(0, 0), Point::new(1, 1)]);
+        assert_eq!(grid.neighbors4_at(far.step(Direction::Down)).count(), 1);
+
+        grid.remove(far);
+        assert_eq!(grid.cells().count(), 2);
+        assert_eq!(SparseGrid::<u8>::new().bounds(), None);
+    }
+}
@@ -0,0 +1,221 @@
+use std::iter::StepBy;
+use std::slice;
+
+use super::Grid;
+
+impl<T: Clone> Grid<T> {
+    // Build a `rows` x `columns` grid whose cell `(row, column)` is the cell `source(row, column)`
+    // of this grid
+    fn remap(
+        &self,
+        rows: usize,
+        columns: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let data = (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (row, column)))
+            .map(|(row, column)| {
+                let (source_row, source_column) = source(row, column);
+                self.data[self.get_index(source_row, source_column)].clone()
+            })
+            .collect();
+        Grid {
+            data,
+            rows,
+            columns,
+            addressing: self.addressing,
+        }
+    }
+
+    // Rows become columns
+    pub fn transpose(&self) -> Self {
+        self.remap(self.columns, self.rows, |row, column| (column, row))
+    }
+
+    // Quarter turn clockwise
+    pub fn rotate_90(&self) -> Self {
+        let last_row = self.rows.saturating_sub(1);
+        self.remap(self.columns, self.rows, |row, column| {
+            (last_row - column, row)
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let (last_row, last_column) = (self.rows.saturating_sub(1), self.columns.saturating_sub(1));
+        self.remap(self.rows, self.columns, |row, column| {
+            (last_row - row, last_column - column)
+        })
+    }
+
+    // Quarter turn counterclockwise
+    pub fn rotate_270(&self) -> Self {
+        let last_column = self.columns.saturating_sub(1);
+        self.remap(self.columns, self.rows, |row, column| {
+            (column, last_column - row)
+        })
+    }
+
+    // Mirror left and right
+    pub fn flip_horizontal(&self) -> Self {
+        let last_column = self.columns.saturating_sub(1);
+        self.remap(self.rows, self.columns, |row, column| {
+            (row, last_column - column)
+        })
+    }
+
+    // Mirror top and bottom
+    pub fn flip_vertical(&self) -> Self {
+        let last_row = self.rows.saturating_sub(1);
+        self.remap(self.rows, self.columns, |row, column| {
+            (last_row - row, column)
+        })
+    }
+
+    // Copy of a `rows` x `columns` rectangle whose top-left corner is `(row, column)`
+    pub fn crop(&self, row: usize, column: usize, rows: usize, columns: usize) -> Option<Self> {
+        self.view(row, column, rows, columns)
+            .map(|view| self.remap(rows, columns, |r, c| (view.top + r, view.left + c)))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.rows {
+            return None;
+        }
+        let start = self.get_index(row, 0);
+        Some(&self.data[start..start + self.columns])
+    }
+
+    // Cells of a column, from top to bottom
+    pub fn column(&self, column: usize) -> Option<StepBy<slice::Iter<'_, T>>> {
+        // Grids without rows have columns but no cells
+        let cells = self.data.get(column..).unwrap_or_default();
+        (column < self.columns).then(|| cells.iter().step_by(self.columns))
+    }
+
+    // Borrowed `rows` x `columns` rectangle whose top-left corner is `(row, column)`
+    pub fn view(
+        &self,
+        row: usize,
+        column: usize,
+        rows: usize,
+        columns: usize,
+    ) -> Option<SubGrid<'_, T>> {
+        let fits = row.checked_add(rows).is_some_and(|end| end <= self.rows)
+            && column
+                .checked_add(columns)
+                .is_some_and(|end| end <= self.columns);
+        fits.then_some(SubGrid {
+            grid: self,
+            top: row,
+            left: column,
+            rows,
+            columns,
+        })
+    }
+}
+
+// Rectangle of a grid, addressed relative to its own top-left corner
+#[derive(Debug)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    top: usize,
+    left: usize,
+    rows: usize,
+    columns: usize,
+}
+
+// Copyable whatever the cell type, like the reference it holds
+impl<T> Clone for SubGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SubGrid<'_, T> {}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        if row >= self.rows || column >= self.columns {
+            return None;
+        }
+        self.grid.get(self.top + row, self.left + column)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&'a [T]> {
+        if row >= self.rows {
+            return None;
+        }
+        let grid_row = self.grid.row(self.top + row)?;
+        Some(&grid_row[self.left..self.left + self.columns])
+    }
+
+    // Cells in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.rows).flat_map(move |row| view.row(row).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(grid: &Grid<u8>) -> String {
+        grid.to_string()
+    }
+
+    #[test]
+    fn test_rotations_and_flips() {
+        let grid = Grid::<u8>::parse("abc\ndef").unwrap();
+        assert_eq!(text(&grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(text(&grid.rotate_90()), "da\neb\nfc\n");
+        assert_eq!(text(&grid.rotate_180()), "fed\ncba\n");
+        assert_eq!(text(&grid.rotate_270()), "cf\nbe\nad\n");
+        assert_eq!(text(&grid.flip_horizontal()), "cba\nfed\n");
+        assert_eq!(text(&grid.flip_vertical()), "def\nabc\n");
+        assert_eq!(
+            text(&grid.rotate_90().rotate_90().rotate_90().rotate_90()),
+            text(&grid)
+        );
+        assert_eq!(
+            text(&grid.rotate_270()),
+            text(&grid.rotate_90().rotate_180())
+        );
+        assert_eq!(Grid::<u8>::new(0, 0, b'.').rotate_90().rows(), 0);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::<u8>::parse("abcd\nefgh\nijkl").unwrap();
+        assert_eq!(grid.row(1), Some(&b"efgh"[..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.column(2).unwrap().copied().collect::<Vec<_>>(), b"cgk");
+        assert!(grid.column(4).is_none());
+        let flat = Grid::new(3, 0, b'.').transpose();
+        assert_eq!((flat.rows(), flat.columns()), (0, 3));
+        assert_eq!(flat.column(1).map(Iterator::count), Some(0));
+
+        let view = grid.view(1, 1, 2, 3).unwrap();
+        assert_eq!((view.rows(), view.columns()), (2, 3));
+        assert_eq!(view.get(1, 0), Some(&b'j'));
+        assert_eq!(view.get(0, 3), None);
+        assert_eq!(view.row(0), Some(&b"fgh"[..]));
+        assert_eq!(view.row(2), None);
+        assert_eq!(view.row(usize::MAX), None);
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), b"fghjkl");
+        assert!(grid.view(2, 0, 2, 1).is_none());
+
+        assert_eq!(text(&grid.crop(0, 2, 3, 2).unwrap()), "cd\ngh\nkl\n");
+        assert!(grid.crop(0, 3, 1, 2).is_none());
+    }
+}
@@ -0,0 +1,281 @@
+use std::ops::RangeInclusive;
+
+// Integers that intervals can be built from: every value has a well-defined neighbor on each side
+pub trait Discrete: Copy + Ord {
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+    // Number of values in `start..=end`, saturating at `usize::MAX`
+    fn count(start: Self, end: Self) -> usize;
+}
+
+macro_rules! impl_discrete_for_integers {
+    ($($integer:ty),*) => {$(
+        impl Discrete for $integer {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> usize {
+                usize::try_from(end.abs_diff(start))
+                    .ok()
+                    .and_then(|difference| difference.checked_add(1))
+                    .unwrap_or(usize::MAX)
+            }
+        }
+    )*};
+}
+
+impl_discrete_for_integers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+// Whether `end` and a range starting at `start` overlap or touch
+fn reaches<T: Discrete>(end: T, start: T) -> bool {
+    end.successor().is_none_or(|next| next >= start)
+}
+
+// Set of integers stored as sorted, disjoint and non-adjacent inclusive ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // Number of disjoint ranges
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    // Number of values covered, saturating at `usize::MAX`
+    pub fn len(&self) -> usize {
+        self.ranges.iter().fold(0, |total: usize, &(start, end)| {
+            total.saturating_add(T::count(start, end))
+        })
+    }
+
+    // Disjoint ranges in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    // Binary search for the only range that may hold `value`
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    // Add a range, merging it with the ranges it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|&(_, other_end)| !reaches(other_end, start));
+        let last = self
+            .ranges
+            .partition_point(|&(other_start, _)| reaches(end, other_start));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    // Take a range out, splitting the ranges it cuts through
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|&(_, other_end)| other_end < start);
+        let last = self
+            .ranges
+            .partition_point(|&(other_start, _)| other_start <= end);
+        if first == last {
+            return;
+        }
+
+        let (first_start, _) = self.ranges[first];
+        let (_, last_end) = self.ranges[last - 1];
+        let before = start
+            .predecessor()
+            .filter(|_| first_start < start)
+            .map(|before_end| (first_start, before_end));
+        let after = end
+            .successor()
+            .filter(|_| end < last_end)
+            .map(|after_start| (after_start, last_end));
+        self.ranges
+            .splice(first..last, before.into_iter().chain(after));
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    // Values in this set but not in `other`
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // Both sets are sorted, so overlaps come out sorted, disjoint and non-adjacent
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(left_start, left_end)), Some(&&(right_start, right_end))) =
+            (left.peek(), right.peek())
+        {
+            let (start, end) = (left_start.max(right_start), left_end.min(right_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if left_end < right_end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    // Values of `bounds` that are not in this set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> IntervalSet<T> {
+        IntervalSet::from_iter([bounds]).difference(self)
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    // Sort once and merge in a single pass, rather than inserting ranges one by one
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut sorted: Vec<(T, T)> = ranges
+            .into_iter()
+            .map(RangeInclusive::into_inner)
+            .filter(|(start, end)| start <= end)
+            .collect();
+        sorted.sort_unstable();
+
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match merged.last_mut() {
+                Some((_, last_end)) if reaches(*last_end, start) => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges<T: Discrete>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = IntervalSet::new();
+        set.insert(10..=14);
+        set.insert(3..=5);
+        set.insert(16..=20);
+        set.insert(12..=18);
+        set.insert(6..=6);
+        // Reversed ranges are empty
+        let (high, low) = (9, 1);
+        set.insert(high..=low);
+        assert_eq!(ranges(&set), [3..=6, 10..=20]);
+        assert_eq!(set.len(), 15);
+        assert_eq!(set.range_count(), 2);
+        assert_eq!(
+            set,
+            [10..=14, 16..=20, 12..=18, 3..=5, 6..=6]
+                .into_iter()
+                .collect()
+        );
+
+        assert!(set.contains(6));
+        assert!(set.contains(20));
+        assert!(!set.contains(7));
+        assert!(!set.contains(21));
+        assert!(!IntervalSet::<u8>::new().contains(0));
+
+        let mut extremes: IntervalSet<u8> = [250..=u8::MAX, 0..=3].into_iter().collect();
+        extremes.insert(4..=10);
+        assert_eq!(ranges(&extremes), [0..=10, 250..=u8::MAX]);
+        assert_eq!(
+            IntervalSet::from_iter([u64::MIN..=u64::MAX]).len(),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut set: IntervalSet<i32> = [-10..=10, 20..=30].into_iter().collect();
+        set.remove(-2..=2);
+        assert_eq!(ranges(&set), [-10..=-3, 3..=10, 20..=30]);
+        set.remove(8..=25);
+        assert_eq!(ranges(&set), [-10..=-3, 3..=7, 26..=30]);
+        set.remove(-20..=-10);
+        set.remove(40..=50);
+        assert_eq!(ranges(&set), [-9..=-3, 3..=7, 26..=30]);
+
+        let mut full: IntervalSet<u8> = [0..=u8::MAX].into_iter().collect();
+        full.remove(0..=0);
+        full.remove(u8::MAX..=u8::MAX);
+        assert_eq!(ranges(&full), [1..=254]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left: IntervalSet<usize> = [1..=5, 10..=15].into_iter().collect();
+        let right: IntervalSet<usize> = [4..=11, 14..=20].into_iter().collect();
+        assert_eq!(ranges(&left.union(&right)), [1..=20]);
+        assert_eq!(
+            ranges(&left.intersection(&right)),
+            [4..=5, 10..=11, 14..=15]
+        );
+        assert_eq!(ranges(&left.difference(&right)), [1..=3, 12..=13]);
+        assert_eq!(ranges(&left.complement(0..=12)), [0..=0, 6..=9]);
+        assert!(left.intersection(&IntervalSet::new()).is_empty());
+    }
+}
@@ -1,5 +1,16 @@
-mod grid;
+pub mod answers;
+pub mod cli;
+pub mod error;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod intervals;
 pub mod puzzles;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 pub mod utils;
 
 pub use grid::Grid;
+pub use intervals::IntervalSet;
@@ -1,9 +1,110 @@
-use advent_of_code_2025::puzzles;
-
-fn main() {
-    puzzles::day1::solve();
-    puzzles::day2::solve();
-    puzzles::day3::solve();
-    puzzles::day4::solve();
-    puzzles::day5::solve();
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+
+use advent_of_code_2025::{
+    answers::Answers,
+    cli::{self, Command, RunOptions},
+    error::Error,
+    examples::{self, ExampleStatus},
+    puzzles, report, runner,
+    scaffold::{self, Layout},
+    solution::Part,
+    utils,
+};
+
+// A missing answers file only means that no answer is known yet
+fn load_answers(path: &Path) -> Result<Answers, String> {
+    match Answers::load(path) {
+        Ok(answers) => Ok(answers),
+        Err(Error::MissingFile { path }) => {
+            eprintln!(
+                "warning: {} does not exist, every answer is unknown",
+                path.display()
+            );
+            Ok(Answers::default())
+        }
+        Err(error) => Err(format!("{}: {error}", path.display())),
+    }
+}
+
+// Solve and render the selected days, returning whether every day succeeded with no wrong answer
+fn run(options: &RunOptions) -> Result<bool, Box<dyn std::error::Error>> {
+    let solutions = options
+        .days
+        .iter()
+        .map(|&day| puzzles::find_day(day).ok_or(cli::CliError::UnknownDay(day)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let answers = options.check.as_deref().map(load_answers).transpose()?;
+
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut reports: Vec<_> = solutions
+        .into_iter()
+        .map(|solution| runner::run_day(solution, &options.source, &parts, options.runs))
+        .collect();
+    if let Some(answers) = &answers {
+        runner::check_reports(&mut reports, answers);
+    }
+
+    let mut out = io::stdout().lock();
+    options.format.render(&reports, &mut out)?;
+    if options.time && !options.format.is_machine_readable() {
+        report::render_timings(&reports, &mut out)?;
+    }
+    if answers.is_some() && !options.format.is_machine_readable() {
+        report::render_check_summary(&reports, &mut out)?;
+    }
+
+    Ok(reports.iter().all(|report| report.succeeded()))
+}
+
+// Solve every example of the selected days, returning whether none failed; unchecked examples are
+// reported but do not fail the run
+fn run_examples(days: &[usize]) -> Result<bool, Box<dyn std::error::Error>> {
+    let reports = examples::run_examples(&utils::examples_dir(), days)?;
+    report::render_examples(&reports, &mut io::stdout().lock())?;
+    Ok(reports
+        .iter()
+        .all(|example| example.status() != ExampleStatus::Failed))
+}
+
+fn scaffold(day: usize) -> Result<bool, Box<dyn std::error::Error>> {
+    let scaffolded = scaffold::scaffold(&Layout::repository(), day)?;
+    for path in &scaffolded.created {
+        println!("created {}", path.display());
+    }
+    println!("updated {}", scaffolded.registry.display());
+    for path in &scaffolded.kept {
+        println!("kept    {}", path.display());
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let outcome = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => run(&options),
+        Ok(Command::Examples(days)) => run_examples(&days),
+        Ok(Command::Scaffold(day)) => scaffold(day),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -1,30 +1,58 @@
-use crate::utils;
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils,
+};
 
 const DIAL_NUMBERS_TOTAL: usize = 100;
 const TARGET_POSITION: usize = 0;
 const DIAL_STARTING_POINT: usize = 50;
-const PUZZLE_NUMBER: usize = 1;
 const DIRECTION_SPLIT_INDEX: usize = 1;
 const DIRECTION_RIGHT: &str = "R";
 const DIRECTION_LEFT: &str = "L";
-const DEFAULT_ROTATION: isize = 0;
 const INITIAL_PASSWORD_COUNT: usize = 0;
 
-fn convert_rotation_to_number(path: &str) -> isize {
-    let (direction, value) = path.split_at(DIRECTION_SPLIT_INDEX);
-    let rotation_value = value.parse::<isize>().unwrap_or(DEFAULT_ROTATION);
+fn convert_rotation_to_number(line_number: usize, path: &str) -> Result<isize> {
+    let Some((direction, value)) = path.split_at_checked(DIRECTION_SPLIT_INDEX) else {
+        return Err(Error::malformed(line_number, 1, "expected `L` or `R`"));
+    };
+    let rotation_value = value.parse::<isize>().map_err(|_| {
+        Error::malformed(
+            line_number,
+            DIRECTION_SPLIT_INDEX + 1,
+            format!("`{value}` is not a valid rotation distance"),
+        )
+    })?;
     match direction {
-        DIRECTION_RIGHT => rotation_value,
-        DIRECTION_LEFT => -rotation_value,
-        _ => DEFAULT_ROTATION,
+        DIRECTION_RIGHT => Ok(rotation_value),
+        DIRECTION_LEFT => Ok(-rotation_value),
+        _ => Err(Error::malformed(
+            line_number,
+            1,
+            format!("unknown direction `{direction}`, expected `L` or `R`"),
+        )),
     }
 }
 
-pub fn solve_part1(puzzle_input: &str) -> usize {
-    let (final_position, password_count) = puzzle_input
-        .split_whitespace()
-        .map(convert_rotation_to_number)
-        .fold(
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Secret Entrance";
+
+    type Input = Vec<isize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input> {
+        utils::numbered_lines(puzzle_input)
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(line_number, line)| convert_rotation_to_number(line_number, line))
+            .collect()
+    }
+
+    fn part1(rotations: &Self::Input) -> Self::Output1 {
+        let (_, password_count) = rotations.iter().fold(
             (DIAL_STARTING_POINT, INITIAL_PASSWORD_COUNT),
             |(acc, count), x| {
                 let new_pos = (acc as isize + x).rem_euclid(DIAL_NUMBERS_TOTAL as isize) as usize;
@@ -39,16 +67,11 @@ pub fn solve_part1(puzzle_input: &str) -> usize {
             },
         );
 
-    println!("Part 1 - Final dial position is: {final_position}");
-    println!("Part 1 - The real password is: {password_count}");
-    password_count
-}
+        password_count
+    }
 
-pub fn solve_part2(puzzle_input: &str) -> usize {
-    let (final_position, password_count) = puzzle_input
-        .split_whitespace()
-        .map(convert_rotation_to_number)
-        .fold(
+    fn part2(rotations: &Self::Input) -> Self::Output2 {
+        let (_, password_count) = rotations.iter().fold(
             (DIAL_STARTING_POINT, INITIAL_PASSWORD_COUNT),
             |(acc, count), x| {
                 let temp_pos = acc as isize + x;
@@ -66,38 +89,49 @@ pub fn solve_part2(puzzle_input: &str) -> usize {
             },
         );
 
-    println!("Part 2 - Final dial position is: {final_position}");
-    println!("Part 2 - The real password is: {password_count}\n");
-    password_count
-}
-
-pub fn solve() {
-    println!("--- DAY {PUZZLE_NUMBER} ---");
-    let puzzle_input = utils::get_puzzle_input_path(PUZZLE_NUMBER);
-    let puzzle_input = str::from_utf8(&puzzle_input).unwrap_or("");
-    solve_part1(puzzle_input);
-    solve_part2(puzzle_input);
+        password_count
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::get_puzzle_dummy_input_path;
+    use crate::utils::get_puzzle_example_input;
 
     use super::*;
 
     #[test]
     fn test_part1() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let password_count = solve_part1(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day1::DAY, "example").unwrap();
+        let password_count = Day1::part1(&Day1::parse(&puzzle_example_input).unwrap());
         assert_eq!(password_count, 3);
     }
 
     #[test]
     fn test_part2() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let password_count = solve_part2(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day1::DAY, "example").unwrap();
+        let password_count = Day1::part2(&Day1::parse(&puzzle_example_input).unwrap());
         assert_eq!(password_count, 6);
     }
+
+    #[test]
+    fn test_malformed_rotation() {
+        let error = Day1::parse("L68\nR48\nX5\n").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MalformedLine {
+                line: 3,
+                column: 1,
+                ..
+            }
+        ));
+        let error = Day1::parse("L68\nRfive").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MalformedLine {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+    }
 }
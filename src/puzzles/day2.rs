@@ -1,6 +1,11 @@
-use crate::utils;
+use std::ops::RangeInclusive;
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils,
+};
 
-const PUZZLE_NUMBER: usize = 2;
 const COMMA_DELIMITER: &str = ",";
 const DASH_DELIMITER: &str = "-";
 
@@ -11,7 +16,7 @@ fn is_invalid_id_part1(id: usize) -> bool {
 }
 
 // Parse a single range fragment like "start-end" into a RangeInclusive<usize>
-pub fn parse_range(fragment: &str) -> Option<std::ops::RangeInclusive<usize>> {
+pub fn parse_range(fragment: &str) -> Option<RangeInclusive<usize>> {
     let parts: Vec<&str> = fragment.split(DASH_DELIMITER).collect();
     if parts.len() != 2 {
         return None;
@@ -61,57 +66,73 @@ fn is_invalid_id_part2(id: usize) -> bool {
     false
 }
 
-fn solve_part1(puzzle_input: &str) -> usize {
-    let invalid_ids_sum: usize = puzzle_input
-        .split(COMMA_DELIMITER)
-        .filter_map(parse_range)
-        .flatten()
-        .filter(|&x| is_invalid_id_part1(x))
-        .sum();
-
-    println!("Part 1 - Invalid IDS sum is: {invalid_ids_sum}");
-    invalid_ids_sum
-}
-
-fn solve_part2(puzzle_input: &str) -> usize {
-    let invalid_ids_sum: usize = puzzle_input
-        .split(COMMA_DELIMITER)
-        .filter_map(parse_range)
-        .flatten()
-        .filter(|&x| is_invalid_id_part2(x))
-        .sum();
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Gift Shop";
+
+    type Input = Vec<RangeInclusive<usize>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input> {
+        let mut id_ranges = Vec::new();
+        for (line_number, line) in utils::numbered_lines(puzzle_input) {
+            let mut column = 1;
+            for fragment in line.split(COMMA_DELIMITER) {
+                if !fragment.trim().is_empty() {
+                    let id_range = parse_range(fragment.trim()).ok_or_else(|| {
+                        Error::malformed(
+                            line_number,
+                            column,
+                            format!("`{fragment}` is not a `start-end` range"),
+                        )
+                    })?;
+                    id_ranges.push(id_range);
+                }
+                column += fragment.len() + COMMA_DELIMITER.len();
+            }
+        }
+        Ok(id_ranges)
+    }
 
-    println!("Part 2 - Invalid IDS sum is: {invalid_ids_sum}\n");
-    invalid_ids_sum
-}
+    fn part1(id_ranges: &Self::Input) -> Self::Output1 {
+        id_ranges
+            .iter()
+            .cloned()
+            .flatten()
+            .filter(|&x| is_invalid_id_part1(x))
+            .sum()
+    }
 
-pub fn solve() {
-    println!("--- DAY {PUZZLE_NUMBER} ---");
-    let puzzle_input = utils::get_puzzle_input_path(PUZZLE_NUMBER);
-    let puzzle_input = str::from_utf8(&puzzle_input).unwrap_or("");
-    solve_part1(puzzle_input);
-    solve_part2(puzzle_input);
+    fn part2(id_ranges: &Self::Input) -> Self::Output2 {
+        id_ranges
+            .iter()
+            .cloned()
+            .flatten()
+            .filter(|&x| is_invalid_id_part2(x))
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::get_puzzle_dummy_input_path;
+    use crate::utils::get_puzzle_example_input;
 
     use super::*;
 
     #[test]
     fn test_part1() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let invalid_ids_sum = solve_part1(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day2::DAY, "example").unwrap();
+        let invalid_ids_sum = Day2::part1(&Day2::parse(&puzzle_example_input).unwrap());
         assert_eq!(invalid_ids_sum, 1227775554);
     }
 
     #[test]
     fn test_part2() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let invalid_ids_sum = solve_part2(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day2::DAY, "example").unwrap();
+        let invalid_ids_sum = Day2::part2(&Day2::parse(&puzzle_example_input).unwrap());
         assert_eq!(invalid_ids_sum, 4174379265);
     }
 }
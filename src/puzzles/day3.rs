@@ -1,8 +1,11 @@
 use std::cmp::Ordering;
 
-use crate::utils;
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils,
+};
 
-const PUZZLE_NUMBER: usize = 3;
 const PART2_BATTERY_NUM: usize = 12;
 const DIGIT_RADIX: u32 = 10;
 
@@ -72,53 +75,75 @@ fn largest_joltage_for_bank_with_n_batteries(bank: &str, batt: usize) -> usize {
     value
 }
 
-fn solve_part1(puzzle_input: &str) -> usize {
-    let total_output_joltage = puzzle_input
-        .split_whitespace()
-        .map(largest_joltage_for_bank_with_two_batteries)
-        .sum();
+pub struct Day3;
 
-    println!("Part 1 - Total output joltage: {total_output_joltage}");
-    total_output_joltage
-}
+impl Solution for Day3 {
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Lobby";
 
-fn solve_part2(puzzle_input: &str) -> usize {
-    let total_output_joltage = puzzle_input
-        .split_whitespace()
-        .map(|bank| largest_joltage_for_bank_with_n_batteries(bank, PART2_BATTERY_NUM))
-        .sum();
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    println!("Part 2 - Total output joltage: {total_output_joltage}\n");
-    total_output_joltage
-}
+    fn parse(puzzle_input: &str) -> Result<Self::Input> {
+        utils::numbered_lines(puzzle_input)
+            .filter(|(_, bank)| !bank.is_empty())
+            .map(|(line_number, bank)| {
+                if let Some(index) = bank.find(|c: char| !c.is_ascii_digit()) {
+                    return Err(Error::malformed(
+                        line_number,
+                        index + 1,
+                        "battery joltage must be a digit",
+                    ));
+                }
+                // Part 2 picks PART2_BATTERY_NUM batteries out of every bank
+                if bank.len() < PART2_BATTERY_NUM {
+                    return Err(Error::malformed(
+                        line_number,
+                        bank.len() + 1,
+                        format!(
+                            "bank has {} batteries, expected at least {PART2_BATTERY_NUM}",
+                            bank.len()
+                        ),
+                    ));
+                }
+                Ok(bank.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input) -> Self::Output1 {
+        banks
+            .iter()
+            .map(|bank| largest_joltage_for_bank_with_two_batteries(bank))
+            .sum()
+    }
 
-pub fn solve() {
-    println!("--- DAY {PUZZLE_NUMBER} ---");
-    let puzzle_input = utils::get_puzzle_input_path(PUZZLE_NUMBER);
-    let puzzle_input = str::from_utf8(&puzzle_input).unwrap_or("");
-    solve_part1(puzzle_input);
-    solve_part2(puzzle_input);
+    fn part2(banks: &Self::Input) -> Self::Output2 {
+        banks
+            .iter()
+            .map(|bank| largest_joltage_for_bank_with_n_batteries(bank, PART2_BATTERY_NUM))
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::get_puzzle_dummy_input_path;
+    use crate::utils::get_puzzle_example_input;
 
     use super::*;
 
     #[test]
     fn test_part1() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let total_output_joltage = solve_part1(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day3::DAY, "example").unwrap();
+        let total_output_joltage = Day3::part1(&Day3::parse(&puzzle_example_input).unwrap());
         assert_eq!(total_output_joltage, 357);
     }
 
     #[test]
     fn test_part2() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let total_output_joltage = solve_part2(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day3::DAY, "example").unwrap();
+        let total_output_joltage = Day3::part2(&Day3::parse(&puzzle_example_input).unwrap());
         assert_eq!(total_output_joltage, 3121910778619);
     }
 }
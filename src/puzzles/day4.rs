@@ -1,97 +1,219 @@
-use crate::{grid::Grid, utils};
+use std::collections::VecDeque;
+
+use crate::{
+    error::{Error, Result},
+    grid::{BitGrid, Grid},
+    solution::Solution,
+};
 
-const PUZZLE_NUMBER: usize = 4;
 const NEIGHBOR_ROLL_THRESHOLD: usize = 4;
 const ROLL_BYTE: u8 = b'@';
 const EMPTY_BYTE: u8 = b'.';
 
-fn solve_part1(puzzle_input: &str) -> usize {
-    let grid = Grid::<u8>::parse(puzzle_input).unwrap();
+fn neighbor_rolls_count(grid: &Grid<u8>, row: usize, column: usize) -> usize {
+    grid.neighbors8(row, column)
+        .filter(|&(_, _, &cell)| cell == ROLL_BYTE)
+        .count()
+}
 
-    let mut n_accessed_rolls = 0;
-    for row in 0..grid.rows() {
-        for column in 0..grid.columns() {
-            if grid.get(row, column) != Some(&ROLL_BYTE) {
-                continue;
-            }
-            let neighbors_rolls_count = grid
-                .find_neighbors(row, column)
-                .iter()
-                .filter(|&coord| *coord == ROLL_BYTE)
-                .count();
-            if neighbors_rolls_count < NEIGHBOR_ROLL_THRESHOLD {
-                n_accessed_rolls += 1;
-            }
+fn rolls(grid: &Grid<u8>) -> BitGrid {
+    BitGrid::from_grid(grid, |&cell| cell == ROLL_BYTE)
+}
+
+// Number of rolls removed by every wave, removing all accessible rolls at once 64 cells at a time
+pub fn wave_sizes(grid: &Grid<u8>) -> Vec<usize> {
+    let mut rolls = rolls(grid);
+    let mut sizes = Vec::new();
+    loop {
+        let accessible = rolls.and(&rolls.fewer_neighbors_than(NEIGHBOR_ROLL_THRESHOLD));
+        if !accessible.any() {
+            return sizes;
         }
+        sizes.push(accessible.count_ones());
+        rolls = rolls.and_not(&accessible);
     }
+}
 
-    println!("Part 1 - Number of accessed rolls by forklift: {n_accessed_rolls}");
-    n_accessed_rolls
+// A roll taken from the pile, listed in removal order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Removal {
+    pub row: usize,
+    pub column: usize,
+    // 1 for the rolls accessible from the start, 2 for the rolls freed by the first wave, ...
+    pub wave: usize,
 }
 
-fn solve_part2(puzzle_input: &str) -> usize {
-    let mut grid = Grid::<u8>::parse(puzzle_input).unwrap();
+// Remove accessible rolls until none is left. Only the neighbors of a removed roll are
+// re-examined, by counting down how many rolls are still around every remaining roll.
+pub fn erode(grid: &Grid<u8>) -> Vec<Removal> {
+    // `None` for empty cells and rolls already queued for removal
+    let mut live_neighbors = Grid::new(grid.rows(), grid.columns(), None);
+    let mut queue = VecDeque::new();
+    for (row, column) in grid.positions_of(&ROLL_BYTE) {
+        let neighbors_rolls_count = neighbor_rolls_count(grid, row, column);
+        if neighbors_rolls_count < NEIGHBOR_ROLL_THRESHOLD {
+            queue.push_back(Removal {
+                row,
+                column,
+                wave: 1,
+            });
+        } else if let Some(count) = live_neighbors.get_mut(row, column) {
+            *count = Some(neighbors_rolls_count);
+        }
+    }
 
-    let mut total_removed_rolls = 0;
-    loop {
-        let mut n_accessed_rolls = 0;
-        for row in 0..grid.rows() {
-            for column in 0..grid.columns() {
-                if grid.get(row, column) != Some(&ROLL_BYTE) {
-                    continue;
-                }
-                let neighbors_rolls_count = grid
-                    .find_neighbors(row, column)
-                    .iter()
-                    .filter(|&coord| *coord == ROLL_BYTE)
-                    .count();
-                if neighbors_rolls_count < NEIGHBOR_ROLL_THRESHOLD {
-                    n_accessed_rolls += 1;
-                    if let Some(current_roll) = grid.get_mut(row, column) {
-                        *current_roll = EMPTY_BYTE;
-                    }
-                }
+    let mut removals = Vec::new();
+    while let Some(removal) = queue.pop_front() {
+        removals.push(removal);
+        for (row, column, _) in grid.neighbors8(removal.row, removal.column) {
+            let Some(cell) = live_neighbors.get_mut(row, column) else {
+                continue;
+            };
+            let Some(count) = cell else {
+                continue;
+            };
+            *count -= 1;
+            if *count < NEIGHBOR_ROLL_THRESHOLD {
+                *cell = None;
+                queue.push_back(Removal {
+                    row,
+                    column,
+                    wave: removal.wave + 1,
+                });
             }
         }
-        if n_accessed_rolls == 0 {
-            break;
-        } else {
-            total_removed_rolls += n_accessed_rolls;
-        }
     }
 
-    println!("Part 2 - Number of removed rolls: {total_removed_rolls}\n");
-    total_removed_rolls
+    removals
 }
 
-pub fn solve() {
-    println!("--- DAY {PUZZLE_NUMBER} ---");
-    let puzzle_input = utils::get_puzzle_input_path(PUZZLE_NUMBER);
-    let puzzle_input = str::from_utf8(&puzzle_input).unwrap_or("");
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Printing Department";
 
-    solve_part1(puzzle_input);
-    solve_part2(puzzle_input);
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input> {
+        // The same untrimmed lines as `Grid::parse`, so that stray spaces are reported where they are
+        for (index, line) in puzzle_input.lines().enumerate() {
+            let line_number = index + 1;
+            if let Some(index) = line
+                .bytes()
+                .position(|byte| byte != ROLL_BYTE && byte != EMPTY_BYTE)
+            {
+                return Err(Error::malformed(
+                    line_number,
+                    index + 1,
+                    "expected `@` or `.`",
+                ));
+            }
+        }
+        Ok(Grid::<u8>::parse(puzzle_input)?)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Output1 {
+        let rolls = rolls(grid);
+        rolls
+            .and(&rolls.fewer_neighbors_than(NEIGHBOR_ROLL_THRESHOLD))
+            .count_ones()
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Output2 {
+        erode(grid).len()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::get_puzzle_dummy_input_path;
+    use crate::grid::{Automaton, Until};
+    use crate::utils::get_puzzle_example_input;
 
     use super::*;
 
     #[test]
     fn test_part1() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let n_accessed_rolls = solve_part1(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day4::DAY, "example").unwrap();
+        let n_accessed_rolls = Day4::part1(&Day4::parse(&puzzle_example_input).unwrap());
         assert_eq!(n_accessed_rolls, 13);
     }
 
     #[test]
     fn test_part2() {
-        let puzzle_dummy_input = get_puzzle_dummy_input_path(PUZZLE_NUMBER);
-        let puzzle_dummy_input = str::from_utf8(&puzzle_dummy_input).unwrap_or("");
-        let total_removed_rolls = solve_part2(puzzle_dummy_input);
+        let puzzle_example_input = get_puzzle_example_input(Day4::DAY, "example").unwrap();
+        let total_removed_rolls = Day4::part2(&Day4::parse(&puzzle_example_input).unwrap());
         assert_eq!(total_removed_rolls, 43);
     }
+
+    #[test]
+    fn test_erosion_waves() {
+        let puzzle_example_input = get_puzzle_example_input(Day4::DAY, "example").unwrap();
+        let grid = Day4::parse(&puzzle_example_input).unwrap();
+        let removals = erode(&grid);
+
+        // Waves are removed in order, and match the generations of a synchronous simulation
+        assert!(removals.windows(2).all(|pair| pair[0].wave <= pair[1].wave));
+        let mut automaton = Automaton::new(grid.clone(), |&cell: &u8, neighborhood| {
+            if cell == ROLL_BYTE
+                && neighborhood.count8(|&neighbor| neighbor == ROLL_BYTE) < NEIGHBOR_ROLL_THRESHOLD
+            {
+                EMPTY_BYTE
+            } else {
+                cell
+            }
+        });
+        let run = automaton.run(Until::FixedPoint);
+        let waves = run.generations() - 1;
+        let per_wave: Vec<usize> = (1..=waves)
+            .map(|wave| {
+                removals
+                    .iter()
+                    .filter(|removal| removal.wave == wave)
+                    .count()
+            })
+            .collect();
+        assert_eq!(per_wave, run.changes[..waves]);
+        assert_eq!(per_wave, wave_sizes(&grid));
+        assert_eq!(per_wave[0], 13);
+        assert!(
+            removals
+                .iter()
+                .all(|removal| grid.get(removal.row, removal.column) == Some(&ROLL_BYTE))
+        );
+    }
+
+    #[test]
+    fn test_malformed_grid() {
+        let error = Day4::parse("..@@\n.@x.\n").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MalformedLine {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+        let error = Day4::parse("..@@\n.@.\n").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MalformedLine {
+                line: 2,
+                column: 4,
+                ..
+            }
+        ));
+        let error = Day4::parse("..@@ \n.@..\n").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MalformedLine {
+                line: 1,
+                column: 5,
+                ..
+            }
+        ));
+        assert_eq!(Day4::parse("..@@\r\n.@..\r\n").unwrap().columns(), 4);
+    }
 }
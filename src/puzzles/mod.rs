@@ -4,4 +4,35 @@ pub mod day3;
 pub mod day4;
 pub mod day5;
 
+use crate::solution::DynSolution;
+
 pub use day2::parse_range;
+
+// Every solved day, in calendar order
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+];
+
+pub fn find_day(day: usize) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|solution| solution.day() == day)
+}
+
+pub fn available_days() -> impl Iterator<Item = usize> {
+    DAYS.iter().map(|solution| solution.day())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_sorted_and_unique() {
+        let days: Vec<usize> = available_days().collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(days.iter().all(|&day| find_day(day).is_some()));
+    }
+}
@@ -0,0 +1,575 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::answers::Verdict;
+use crate::examples::{ExampleReport, ExampleStatus};
+use crate::runner::{DayReport, PartReport, Timing};
+use crate::solution::Answer;
+
+const TIMING_HEADERS: [&str; 5] = ["Day", "Step", "Min", "Median", "Mean"];
+const COLUMN_SEPARATOR: &str = "  ";
+const RECORD_FIELDS: [&str; 15] = [
+    "day",
+    "title",
+    "part",
+    "status",
+    "answer",
+    "expected",
+    "input",
+    "runs",
+    "parse_min_ns",
+    "parse_median_ns",
+    "parse_mean_ns",
+    "solve_min_ns",
+    "solve_median_ns",
+    "solve_mean_ns",
+    "error",
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    // Human friendly sections, one per day
+    #[default]
+    Pretty,
+    // One tab separated `day part answer [verdict]` line per solved part, `day error message` on failure
+    Plain,
+    // An array with one object per solved part (or per failed day)
+    Json,
+    // A header line, then one row per solved part (or per failed day)
+    Csv,
+}
+
+impl Format {
+    pub const NAMES: [&'static str; 4] = ["pretty", "plain", "json", "csv"];
+
+    pub fn render<W: Write>(self, reports: &[DayReport], out: &mut W) -> io::Result<()> {
+        match self {
+            Format::Pretty => render_pretty(reports, out),
+            Format::Plain => render_plain(reports, out),
+            Format::Json => render_json(reports, out),
+            Format::Csv => render_csv(reports, out),
+        }
+    }
+
+    // Machine-readable formats already carry the timings and must not be followed by prose
+    pub const fn is_machine_readable(self) -> bool {
+        matches!(self, Format::Json | Format::Csv)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFormat(pub String);
+
+impl fmt::Display for UnknownFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown format `{}` (expected one of: {})",
+            self.0,
+            Format::NAMES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownFormat {}
+
+impl FromStr for Format {
+    type Err = UnknownFormat;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "pretty" => Ok(Format::Pretty),
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(UnknownFormat(name.to_string())),
+        }
+    }
+}
+
+fn render_pretty<W: Write>(reports: &[DayReport], out: &mut W) -> io::Result<()> {
+    for report in reports {
+        writeln!(out, "--- DAY {}: {} ---", report.day, report.title)?;
+        match &report.outcome {
+            Ok(solved) => {
+                for part in &solved.parts {
+                    writeln!(out, "{}", describe_part(part))?;
+                }
+            }
+            Err(error) => writeln!(out, "Error: {error}")?,
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+// `Part 1: 3`, followed by the verdict when answers are checked
+fn describe_part(part: &PartReport) -> String {
+    let answer = format!("Part {}: {}", part.part.number(), part.answer);
+    match &part.verdict {
+        Some(Verdict::Wrong { expected }) => format!("{answer} (wrong, expected {expected})"),
+        Some(verdict) => format!("{answer} ({})", verdict.name()),
+        None => answer,
+    }
+}
+
+fn render_plain<W: Write>(reports: &[DayReport], out: &mut W) -> io::Result<()> {
+    for report in reports {
+        match &report.outcome {
+            Ok(solved) => {
+                for part in &solved.parts {
+                    write!(
+                        out,
+                        "{}\t{}\t{}",
+                        report.day,
+                        part.part.number(),
+                        part.answer
+                    )?;
+                    match &part.verdict {
+                        Some(verdict) => writeln!(out, "\t{}", verdict.name())?,
+                        None => writeln!(out)?,
+                    }
+                }
+            }
+            Err(error) => writeln!(out, "{}\terror\t{error}", report.day)?,
+        }
+    }
+    Ok(())
+}
+
+// A single value of a JSON / CSV record
+enum Field {
+    Null,
+    Integer(i128),
+    Text(String),
+}
+
+impl From<&Answer> for Field {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Unsigned(value) => Field::Integer(*value as i128),
+            Answer::Signed(value) => Field::Integer(*value as i128),
+            Answer::Text(value) => Field::Text(value.clone()),
+        }
+    }
+}
+
+fn timing_fields(timing: Option<&Timing>) -> [Field; 3] {
+    match timing {
+        Some(timing) => [timing.min, timing.median, timing.mean]
+            .map(|duration| Field::Integer(duration.as_nanos() as i128)),
+        None => [Field::Null, Field::Null, Field::Null],
+    }
+}
+
+// Flatten the reports into records whose fields follow RECORD_FIELDS
+fn records(reports: &[DayReport]) -> Vec<Vec<Field>> {
+    let mut records = Vec::new();
+    for report in reports {
+        let common = || {
+            vec![
+                Field::Integer(report.day as i128),
+                Field::Text(report.title.to_string()),
+            ]
+        };
+        match &report.outcome {
+            Ok(solved) => {
+                for part in &solved.parts {
+                    let (status, expected) = match &part.verdict {
+                        Some(Verdict::Correct) => ("correct", Field::from(&part.answer)),
+                        Some(Verdict::Wrong { expected }) => ("wrong", Field::from(expected)),
+                        Some(Verdict::Unknown) => ("unknown", Field::Null),
+                        None => ("ok", Field::Null),
+                    };
+                    let mut record = common();
+                    record.extend([
+                        Field::Integer(part.part.number() as i128),
+                        Field::Text(status.to_string()),
+                        Field::from(&part.answer),
+                        expected,
+                        Field::Text(report.input.clone()),
+                        Field::Integer(part.timing.runs as i128),
+                    ]);
+                    record.extend(timing_fields(Some(&solved.parse_timing)));
+                    record.extend(timing_fields(Some(&part.timing)));
+                    record.push(Field::Null);
+                    records.push(record);
+                }
+            }
+            Err(error) => {
+                let mut record = common();
+                record.extend([
+                    Field::Null,
+                    Field::Text("error".to_string()),
+                    Field::Null,
+                    Field::Null,
+                    Field::Text(report.input.clone()),
+                    Field::Null,
+                ]);
+                record.extend(timing_fields(None));
+                record.extend(timing_fields(None));
+                record.push(Field::Text(error.to_string()));
+                records.push(record);
+            }
+        }
+    }
+    records
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_json<W: Write>(reports: &[DayReport], out: &mut W) -> io::Result<()> {
+    let records = records(reports);
+    writeln!(out, "[")?;
+    for (index, record) in records.iter().enumerate() {
+        let fields = RECORD_FIELDS
+            .iter()
+            .zip(record)
+            .map(|(name, field)| {
+                let value = match field {
+                    Field::Null => "null".to_string(),
+                    Field::Integer(value) => value.to_string(),
+                    Field::Text(value) => json_string(value),
+                };
+                format!("{}: {value}", json_string(name))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let separator = if index + 1 < records.len() { "," } else { "" };
+        writeln!(out, "  {{{fields}}}{separator}")?;
+    }
+    writeln!(out, "]")
+}
+
+fn render_csv<W: Write>(reports: &[DayReport], out: &mut W) -> io::Result<()> {
+    writeln!(out, "{}", RECORD_FIELDS.join(","))?;
+    for record in records(reports) {
+        let cells = record
+            .iter()
+            .map(|field| match field {
+                Field::Null => String::new(),
+                Field::Integer(value) => value.to_string(),
+                Field::Text(value) => csv_cell(value),
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(out, "{cells}")?;
+    }
+    Ok(())
+}
+
+// One line counting correct, wrong and unknown answers, plus days that failed to run
+pub fn render_check_summary<W: Write>(reports: &[DayReport], out: &mut W) -> io::Result<()> {
+    let (mut correct, mut wrong, mut unknown, mut failed) = (0, 0, 0, 0);
+    for report in reports {
+        match &report.outcome {
+            Ok(solved) => {
+                for part in &solved.parts {
+                    match part.verdict {
+                        Some(Verdict::Correct) => correct += 1,
+                        Some(Verdict::Wrong { .. }) => wrong += 1,
+                        Some(Verdict::Unknown) | None => unknown += 1,
+                    }
+                }
+            }
+            Err(_) => failed += 1,
+        }
+    }
+    writeln!(
+        out,
+        "Check: {correct} correct, {wrong} wrong, {unknown} unknown, {failed} failed day(s)"
+    )
+}
+
+// One PASS / FAIL / UNCHECKED line per example file, then the number of examples of every status
+pub fn render_examples<W: Write>(reports: &[ExampleReport], out: &mut W) -> io::Result<()> {
+    let labels: Vec<String> = reports
+        .iter()
+        .map(|example| {
+            let file_name = example.example.path.file_name().unwrap_or_default();
+            format!("day{}/{}", example.example.day, file_name.display())
+        })
+        .collect();
+    let width = labels.iter().map(String::len).max().unwrap_or_default();
+    let statuses: Vec<ExampleStatus> = reports.iter().map(ExampleReport::status).collect();
+    let status_width = statuses
+        .iter()
+        .map(|status| status.label().len())
+        .max()
+        .unwrap_or_default();
+
+    for ((example, label), status) in reports.iter().zip(&labels).zip(&statuses) {
+        let details = match &example.report.outcome {
+            Ok(solved) => solved
+                .parts
+                .iter()
+                .map(describe_part)
+                .collect::<Vec<_>>()
+                .join(", "),
+            Err(error) => format!("Error: {error}"),
+        };
+        writeln!(
+            out,
+            "{:<status_width$}  {label:<width$}  {details}",
+            status.label()
+        )?;
+    }
+
+    let count = |wanted: ExampleStatus| statuses.iter().filter(|&&status| status == wanted).count();
+    write!(
+        out,
+        "{} passed, {} failed",
+        count(ExampleStatus::Passed),
+        count(ExampleStatus::Failed)
+    )?;
+    match count(ExampleStatus::Unchecked) {
+        0 => writeln!(out),
+        unchecked => writeln!(out, ", {unchecked} unchecked"),
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+fn timing_row(day: String, step: String, timing: &Timing) -> [String; 5] {
+    [
+        day,
+        step,
+        format_duration(timing.min),
+        format_duration(timing.median),
+        format_duration(timing.mean),
+    ]
+}
+
+// Aligned table of the parse and solve durations of every solved day, followed by their total
+pub fn render_timings<W: Write>(reports: &[DayReport], out: &mut W) -> io::Result<()> {
+    let mut rows = vec![TIMING_HEADERS.map(str::to_string)];
+    let mut total = Timing::default();
+    let mut runs = 0;
+
+    for report in reports {
+        let Ok(solved) = &report.outcome else {
+            continue;
+        };
+        let steps = std::iter::once(("parse".to_string(), &solved.parse_timing)).chain(
+            solved
+                .parts
+                .iter()
+                .map(|part| (format!("part {}", part.part.number()), &part.timing)),
+        );
+        for (step, timing) in steps {
+            rows.push(timing_row(report.day.to_string(), step, timing));
+            total.min += timing.min;
+            total.median += timing.median;
+            total.mean += timing.mean;
+            runs = runs.max(timing.runs);
+        }
+    }
+    rows.push(timing_row(String::new(), "total".to_string(), &total));
+
+    let mut widths = [0; TIMING_HEADERS.len()];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    writeln!(out, "Timings over {runs} run(s):")?;
+    let last_row = rows.len() - 1;
+    for (index, row) in rows.iter().enumerate() {
+        if index == last_row {
+            let table_width =
+                widths.iter().sum::<usize>() + COLUMN_SEPARATOR.len() * (widths.len() - 1);
+            writeln!(out, "{}", "-".repeat(table_width))?;
+        }
+        // Step names are left aligned, every other column holds numbers
+        let line = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| match column {
+                1 => format!("{cell:<width$}"),
+                _ => format!("{cell:>width$}"),
+            })
+            .collect::<Vec<_>>()
+            .join(COLUMN_SEPARATOR);
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+    use crate::error::Error;
+    use crate::runner::{self, SolvedDay};
+    use crate::solution::Part;
+
+    fn timing(micros: u64) -> Timing {
+        let duration = Duration::from_micros(micros);
+        Timing {
+            runs: 1,
+            min: duration,
+            median: duration,
+            mean: duration,
+        }
+    }
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 1,
+                title: "Secret Entrance",
+                input: "inputs/day1.txt".to_string(),
+                outcome: Ok(SolvedDay {
+                    parse_timing: timing(2),
+                    parts: vec![
+                        PartReport {
+                            part: Part::One,
+                            answer: 3usize.into(),
+                            timing: timing(30),
+                            verdict: None,
+                        },
+                        PartReport {
+                            part: Part::Two,
+                            answer: 6usize.into(),
+                            timing: timing(1500),
+                            verdict: None,
+                        },
+                    ],
+                }),
+            },
+            DayReport {
+                day: 2,
+                title: "Gift Shop",
+                input: "inputs/day2.txt".to_string(),
+                outcome: Err(Error::malformed(1, 4, "`x` is not a `start-end` range")),
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = Vec::new();
+        format.render(&reports(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_render_formats() {
+        assert_eq!(
+            render(Format::Pretty),
+            "--- DAY 1: Secret Entrance ---\nPart 1: 3\nPart 2: 6\n\n\
+             --- DAY 2: Gift Shop ---\nError: line 1, column 4: `x` is not a `start-end` range\n\n"
+        );
+        assert_eq!(
+            render(Format::Plain),
+            "1\t1\t3\n1\t2\t6\n2\terror\tline 1, column 4: `x` is not a `start-end` range\n"
+        );
+    }
+
+    #[test]
+    fn test_render_machine_readable_formats() {
+        assert_eq!(
+            render(Format::Json),
+            r#"[
+  {"day": 1, "title": "Secret Entrance", "part": 1, "status": "ok", "answer": 3, "expected": null, "input": "inputs/day1.txt", "runs": 1, "parse_min_ns": 2000, "parse_median_ns": 2000, "parse_mean_ns": 2000, "solve_min_ns": 30000, "solve_median_ns": 30000, "solve_mean_ns": 30000, "error": null},
+  {"day": 1, "title": "Secret Entrance", "part": 2, "status": "ok", "answer": 6, "expected": null, "input": "inputs/day1.txt", "runs": 1, "parse_min_ns": 2000, "parse_median_ns": 2000, "parse_mean_ns": 2000, "solve_min_ns": 1500000, "solve_median_ns": 1500000, "solve_mean_ns": 1500000, "error": null},
+  {"day": 2, "title": "Gift Shop", "part": null, "status": "error", "answer": null, "expected": null, "input": "inputs/day2.txt", "runs": null, "parse_min_ns": null, "parse_median_ns": null, "parse_mean_ns": null, "solve_min_ns": null, "solve_median_ns": null, "solve_mean_ns": null, "error": "line 1, column 4: `x` is not a `start-end` range"}
+]
+"#
+        );
+        assert_eq!(
+            render(Format::Csv),
+            r#"day,title,part,status,answer,expected,input,runs,parse_min_ns,parse_median_ns,parse_mean_ns,solve_min_ns,solve_median_ns,solve_mean_ns,error
+1,Secret Entrance,1,ok,3,,inputs/day1.txt,1,2000,2000,2000,30000,30000,30000,
+1,Secret Entrance,2,ok,6,,inputs/day1.txt,1,2000,2000,2000,1500000,1500000,1500000,
+2,Gift Shop,,error,,,inputs/day2.txt,,,,,,,,"line 1, column 4: `x` is not a `start-end` range"
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_verdicts() {
+        let mut reports = reports();
+        let answers = Answers::parse("[day1]\npart1 = 3\n").unwrap();
+        runner::check_reports(&mut reports, &answers);
+        if let Ok(solved) = &mut reports[0].outcome {
+            solved.parts[1].verdict = Some(Verdict::Wrong {
+                expected: 5usize.into(),
+            });
+        }
+
+        let mut out = Vec::new();
+        Format::Pretty.render(&reports[..1], &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "--- DAY 1: Secret Entrance ---\nPart 1: 3 (correct)\nPart 2: 6 (wrong, expected 5)\n\n"
+        );
+
+        let mut out = Vec::new();
+        Format::Csv.render(&reports[..1], &mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        assert!(csv.contains("\n1,Secret Entrance,1,correct,3,3,"));
+        assert!(csv.contains("\n1,Secret Entrance,2,wrong,6,5,"));
+
+        let mut out = Vec::new();
+        render_check_summary(&reports, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Check: 1 correct, 1 wrong, 0 unknown, 1 failed day(s)\n"
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\c\nd"), r#""a\"b\\c\nd""#);
+        assert_eq!(csv_cell("plain"), "plain");
+        assert_eq!(csv_cell("a,\"b\""), r#""a,""b""""#);
+    }
+
+    #[test]
+    fn test_render_timings() {
+        let mut out = Vec::new();
+        render_timings(&reports(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+Timings over 1 run(s):
+Day  Step        Min   Median     Mean
+  1  parse    2.00µs   2.00µs   2.00µs
+  1  part 1  30.00µs  30.00µs  30.00µs
+  1  part 2   1.50ms   1.50ms   1.50ms
+--------------------------------------
+     total    1.53ms   1.53ms   1.53ms
+"
+        );
+    }
+}
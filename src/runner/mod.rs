@@ -0,0 +1,184 @@
+use std::time::{Duration, Instant};
+
+use crate::answers::{Answers, Verdict};
+use crate::error::{Error, Result};
+use crate::solution::{Answer, DynSolution, Part};
+use crate::utils::InputSource;
+
+// Summary of the wall-clock durations measured over one or more runs of the same step
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timing {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        if samples.is_empty() {
+            return Timing::default();
+        }
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let mid = runs / 2;
+        let median = if runs.is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+
+        Timing {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+        }
+    }
+}
+
+// Run `step` `runs` times (at least once), keeping the result of the last run
+fn measure<T>(runs: usize, mut step: impl FnMut() -> T) -> (T, Timing) {
+    let mut samples = Vec::with_capacity(runs.max(1));
+    let mut result;
+    loop {
+        let start = Instant::now();
+        result = step();
+        samples.push(start.elapsed());
+        if samples.len() >= runs {
+            break;
+        }
+    }
+    (result, Timing::from_samples(&mut samples))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub timing: Timing,
+    // Only set when the answers are checked against known ones
+    pub verdict: Option<Verdict>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolvedDay {
+    pub parse_timing: Timing,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: usize,
+    pub title: &'static str,
+    pub input: String,
+    pub outcome: Result<SolvedDay>,
+}
+
+impl DayReport {
+    pub fn error(&self) -> Option<&Error> {
+        self.outcome.as_ref().err()
+    }
+
+    // The day ran and none of its checked answers is wrong
+    pub fn succeeded(&self) -> bool {
+        self.outcome.as_ref().is_ok_and(|solved| {
+            solved
+                .parts
+                .iter()
+                .all(|part| !matches!(part.verdict, Some(Verdict::Wrong { .. })))
+        })
+    }
+}
+
+// Parse the puzzle input and solve every requested part from it, timing each step over `runs` runs
+pub fn solve_day(
+    solution: &dyn DynSolution,
+    puzzle_input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<SolvedDay> {
+    let (input, parse_timing) = measure(runs, || solution.parse(puzzle_input));
+    let input = input?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, timing) = measure(runs, || solution.solve(input.as_ref(), part));
+            PartReport {
+                part,
+                answer,
+                timing,
+                verdict: None,
+            }
+        })
+        .collect();
+
+    Ok(SolvedDay {
+        parse_timing,
+        parts,
+    })
+}
+
+// Load the input of a day and solve it, keeping any failure inside the report
+pub fn run_day(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    parts: &[Part],
+    runs: usize,
+) -> DayReport {
+    let outcome = source
+        .load(solution.day())
+        .and_then(|puzzle_input| solve_day(solution, &puzzle_input, parts, runs));
+
+    DayReport {
+        day: solution.day(),
+        title: solution.title(),
+        input: source.describe(solution.day()),
+        outcome,
+    }
+}
+
+// Compare every solved part with the recorded answers
+pub fn check_reports(reports: &mut [DayReport], answers: &Answers) {
+    for report in reports {
+        if let Ok(solved) = &mut report.outcome {
+            for part in &mut solved.parts {
+                part.verdict = Some(answers.verdict(report.day, part.part, &part.answer));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_statistics() {
+        let mut samples = [5, 1, 3, 7].map(Duration::from_millis);
+        let timing = Timing::from_samples(&mut samples);
+        assert_eq!(timing.runs, 4);
+        assert_eq!(timing.min, Duration::from_millis(1));
+        assert_eq!(timing.median, Duration::from_millis(4));
+        assert_eq!(timing.mean, Duration::from_millis(4));
+
+        let mut samples = [9, 2, 4].map(Duration::from_millis);
+        let timing = Timing::from_samples(&mut samples);
+        assert_eq!(timing.median, Duration::from_millis(4));
+        assert_eq!(timing.mean, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_measure_repeats_steps() {
+        let mut calls = 0;
+        let (result, timing) = measure(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!((result, timing.runs), (3, 3));
+        assert_eq!(measure(0, || ()).1.runs, 1);
+    }
+}
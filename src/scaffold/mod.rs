@@ -0,0 +1,306 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::utils;
+
+// Advent of Code 2025 has twelve puzzles
+pub const LAST_DAY: usize = 12;
+
+const TEMPLATE: &str = include_str!("day.rs.template");
+const DAY_PLACEHOLDER: &str = "{DAY}";
+const REGISTRY_FILE_NAME: &str = "mod.rs";
+const MODULE_PREFIX: &str = "pub mod day";
+const REGISTRY_ENTRY_PREFIX: &str = "&day";
+const EXAMPLE_NAME: &str = "example";
+// The generated tests fail until the expected answers are recorded here
+const EXAMPLE_ANSWERS: &str = "# Expected answers of example.txt, e.g. `part1 = 42`\n";
+
+// Where the files of a new day are generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    // Holds one `day<N>.rs` module per day and the `mod.rs` registry
+    pub puzzles_dir: PathBuf,
+    pub input_dir: PathBuf,
+    pub examples_dir: PathBuf,
+}
+
+impl Layout {
+    // The sources and examples of this repository, with inputs in the default input directory
+    pub fn repository() -> Self {
+        Layout {
+            puzzles_dir: Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("src")
+                .join("puzzles"),
+            input_dir: utils::default_input_dir(),
+            examples_dir: utils::examples_dir(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(usize),
+    AlreadyExists(PathBuf),
+    AlreadyRegistered(usize),
+    // The registry has no `pub mod day<N>;` declaration or `&day<N>::Day<N>` entry to insert next to
+    UnknownRegistryLayout(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => {
+                write!(f, "day must be between 1 and {LAST_DAY}, got {day}")
+            }
+            ScaffoldError::AlreadyExists(path) => {
+                write!(
+                    f,
+                    "{} already exists, refusing to overwrite it",
+                    path.display()
+                )
+            }
+            ScaffoldError::AlreadyRegistered(day) => {
+                write!(f, "day {day} is already registered in the puzzles module")
+            }
+            ScaffoldError::UnknownRegistryLayout(path) => write!(
+                f,
+                "{} has no day module declaration or registry entry to insert next to",
+                path.display()
+            ),
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// Files written by the scaffolding of a day
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    // The `mod.rs` registry the day was added to
+    pub registry: PathBuf,
+    // Inputs and examples that already existed are left untouched
+    pub kept: Vec<PathBuf>,
+}
+
+// Day number at the start of `line` once `prefix` is stripped, e.g. 4 for `pub mod day4;`
+fn line_day(line: &str, prefix: &str) -> Option<usize> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..digits].parse().ok()
+}
+
+// Insert a line among the ones starting with `prefix`, keeping them sorted by day
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    prefix: &str,
+    day: usize,
+    line: impl Fn(&str) -> String,
+) -> bool {
+    let days: Vec<(usize, usize)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, line_day(line, prefix)?)))
+        .collect();
+    let Some(&(last_index, _)) = days.last() else {
+        return false;
+    };
+    let index = days
+        .iter()
+        .find(|(_, other_day)| *other_day > day)
+        .map_or(last_index + 1, |&(index, _)| index);
+
+    // Reuse the indentation of the neighbouring declarations
+    let neighbour = &lines[index.min(last_index)];
+    let indent = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+    let new_line = line(indent);
+    lines.insert(index, new_line);
+    true
+}
+
+// Declare the module of a day and add it to `DAYS`, or `None` if the registry layout is unknown
+fn register(registry: &str, day: usize) -> Option<String> {
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+    let declared = insert_sorted(&mut lines, MODULE_PREFIX, day, |indent| {
+        format!("{indent}{MODULE_PREFIX}{day};")
+    });
+    let listed = insert_sorted(&mut lines, REGISTRY_ENTRY_PREFIX, day, |indent| {
+        format!("{indent}{REGISTRY_ENTRY_PREFIX}{day}::Day{day},")
+    });
+    if !(declared && listed) {
+        return None;
+    }
+
+    let mut registry_with_day = lines.join("\n");
+    if registry.ends_with('\n') {
+        registry_with_day.push('\n');
+    }
+    Some(registry_with_day)
+}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> ScaffoldError + '_ {
+    move |source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+// Write a new file, failing instead of overwriting an existing one
+fn create_file(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error(parent))?;
+    }
+    let mut file = fs::File::create_new(path).map_err(|source| match source.kind() {
+        io::ErrorKind::AlreadyExists => ScaffoldError::AlreadyExists(path.to_path_buf()),
+        _ => io_error(path)(source),
+    })?;
+    file.write_all(contents.as_bytes()).map_err(io_error(path))
+}
+
+// Generate the module, registry entry, empty input and example files of a new day. Nothing is
+// written when the module already exists or the day is already registered.
+pub fn scaffold(layout: &Layout, day: usize) -> Result<Scaffold, ScaffoldError> {
+    if !(1..=LAST_DAY).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let module_path = layout.puzzles_dir.join(format!("day{day}.rs"));
+    if module_path.exists() {
+        return Err(ScaffoldError::AlreadyExists(module_path));
+    }
+    let registry_path = layout.puzzles_dir.join(REGISTRY_FILE_NAME);
+    let registry = fs::read_to_string(&registry_path).map_err(io_error(&registry_path))?;
+    if registry
+        .lines()
+        .any(|line| line_day(line, MODULE_PREFIX) == Some(day))
+    {
+        return Err(ScaffoldError::AlreadyRegistered(day));
+    }
+    let registry = register(&registry, day)
+        .ok_or_else(|| ScaffoldError::UnknownRegistryLayout(registry_path.clone()))?;
+
+    let mut scaffold = Scaffold::default();
+    create_file(
+        &module_path,
+        &TEMPLATE.replace(DAY_PLACEHOLDER, &day.to_string()),
+    )?;
+    scaffold.created.push(module_path);
+    fs::write(&registry_path, registry).map_err(io_error(&registry_path))?;
+    scaffold.registry = registry_path;
+
+    let example_path = utils::day_examples_dir(&layout.examples_dir, day).join(EXAMPLE_NAME);
+    let inputs = [
+        (utils::day_input_path(&layout.input_dir, day), ""),
+        (example_path.with_extension("txt"), ""),
+        (example_path.with_extension("toml"), EXAMPLE_ANSWERS),
+    ];
+    for (path, contents) in inputs {
+        match create_file(&path, contents) {
+            Ok(()) => scaffold.created.push(path),
+            Err(ScaffoldError::AlreadyExists(path)) => scaffold.kept.push(path),
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(scaffold)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const REGISTRY: &str = "\
+pub mod day1;
+pub mod day4;
+
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day4::Day4,
+];
+";
+
+    #[test]
+    fn test_register_keeps_days_sorted() {
+        assert_eq!(
+            register(REGISTRY, 2).unwrap(),
+            "\
+pub mod day1;
+pub mod day2;
+pub mod day4;
+
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day4::Day4,
+];
+"
+        );
+        let registry = register(REGISTRY, 12).unwrap();
+        assert!(registry.contains("pub mod day4;\npub mod day12;\n"));
+        assert!(registry.contains("    &day4::Day4,\n    &day12::Day12,\n];"));
+        assert_eq!(register("pub static DAYS: &[u8] = &[];", 2), None);
+    }
+
+    #[test]
+    fn test_scaffold_refuses_to_overwrite() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let layout = Layout {
+            puzzles_dir: root.join("puzzles"),
+            input_dir: root.join("inputs"),
+            examples_dir: root.join("examples"),
+        };
+        fs::create_dir_all(&layout.puzzles_dir).unwrap();
+        fs::create_dir_all(&layout.input_dir).unwrap();
+        fs::write(layout.puzzles_dir.join(REGISTRY_FILE_NAME), REGISTRY).unwrap();
+        fs::write(layout.input_dir.join("day6.txt"), "downloaded input").unwrap();
+
+        let scaffolded = scaffold(&layout, 6).unwrap();
+        assert_eq!(scaffolded.created.len(), 3);
+        assert_eq!(scaffolded.kept, [layout.input_dir.join("day6.txt")]);
+        let module = fs::read_to_string(layout.puzzles_dir.join("day6.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day6 {"));
+        assert!(!module.contains(DAY_PLACEHOLDER));
+        assert_eq!(
+            fs::read_to_string(layout.input_dir.join("day6.txt")).unwrap(),
+            "downloaded input"
+        );
+        assert!(
+            utils::day_examples_dir(&layout.examples_dir, 6)
+                .join("example.txt")
+                .exists()
+        );
+
+        assert!(matches!(
+            scaffold(&layout, 6),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        fs::remove_file(layout.puzzles_dir.join("day6.rs")).unwrap();
+        assert!(matches!(
+            scaffold(&layout, 6),
+            Err(ScaffoldError::AlreadyRegistered(6))
+        ));
+        assert!(matches!(
+            scaffold(&layout, 13),
+            Err(ScaffoldError::InvalidDay(13))
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
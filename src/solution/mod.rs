@@ -0,0 +1,116 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::error::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub const fn number(self) -> usize {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+// Typed answer of a single part, independent of how it is later rendered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident as $target:ty: $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_answer_from!(Unsigned as u64: u8, u16, u32, u64, usize);
+impl_answer_from!(Signed as i64: i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+// A single day of the calendar: parse the raw puzzle input once, then solve both parts from it
+pub trait Solution {
+    const DAY: usize;
+    const TITLE: &'static str;
+
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+// Object-safe counterpart of `Solution`, so days with different input types fit in one registry
+pub trait DynSolution: Sync {
+    fn day(&self) -> usize;
+    fn title(&self) -> &'static str;
+    fn parse(&self, puzzle_input: &str) -> Result<Box<dyn Any>>;
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, puzzle_input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(puzzle_input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .unwrap_or_else(|| panic!("Input was not parsed by day {}", S::DAY));
+        match part {
+            Part::One => S::part1(input).into(),
+            Part::Two => S::part2(input).into(),
+        }
+    }
+}
@@ -1,24 +1,123 @@
-use std::{fs, path::Path};
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
 
-const PUZZLE_INPUT_PREFIX: &str = "puzzles_inputs/real/day";
-const PUZZLE_DUMMY_INPUT_PREFIX: &str = "puzzles_inputs/dummy/day";
-const PUZZLE_INPUT_SUFFIX: &str = ".txt";
+use crate::error::{Error, Result};
 
-pub fn get_puzzle_input_path(day: usize) -> Vec<u8> {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+pub const INPUT_DIR_ENV_VAR: &str = "AOC_INPUT_DIR";
 
-    let puzzle_input_path =
-        Path::new(manifest_dir).join(format!("{PUZZLE_INPUT_PREFIX}{day}{PUZZLE_INPUT_SUFFIX}"));
-    fs::read(puzzle_input_path)
-        .unwrap_or_else(|_| panic!("Unexpected error reading puzzle input for day {day}"))
+const PUZZLE_INPUTS_DIR: &str = "puzzles_inputs";
+const PUZZLE_REAL_INPUTS_DIR: &str = "real";
+const PUZZLE_EXAMPLES_DIR: &str = "examples";
+const PUZZLE_INPUT_PREFIX: &str = "day";
+pub const PUZZLE_INPUT_SUFFIX: &str = ".txt";
+const STDIN_NAME: &str = "<stdin>";
+
+// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // A directory holding one `day<N>.txt` file per day
+    Directory(PathBuf),
+    // A single file, whatever its name
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn load(&self, day: usize) -> Result<String> {
+        match self {
+            InputSource::Directory(input_dir) => read_input_file(&day_input_path(input_dir, day)),
+            InputSource::File(path) => read_input_file(path),
+            InputSource::Stdin => read_stdin(),
+        }
+    }
+
+    // Human readable location of the input of a day, e.g. for reports
+    pub fn describe(&self, day: usize) -> String {
+        match self {
+            InputSource::Directory(input_dir) => {
+                day_input_path(input_dir, day).display().to_string()
+            }
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => STDIN_NAME.to_string(),
+        }
+    }
+}
+
+fn manifest_inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(PUZZLE_INPUTS_DIR)
+}
+
+// The `AOC_INPUT_DIR` environment variable wins over the inputs shipped with the repository
+pub fn default_input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_ENV_VAR)
+        .filter(|input_dir| !input_dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_inputs_dir().join(PUZZLE_REAL_INPUTS_DIR))
+}
+
+pub fn day_input_path(input_dir: &Path, day: usize) -> PathBuf {
+    input_dir.join(format!("{PUZZLE_INPUT_PREFIX}{day}{PUZZLE_INPUT_SUFFIX}"))
+}
+
+// Examples are grouped in one `day<N>` directory per day
+pub fn examples_dir() -> PathBuf {
+    manifest_inputs_dir().join(PUZZLE_EXAMPLES_DIR)
 }
 
-pub fn get_puzzle_dummy_input_path(day: usize) -> Vec<u8> {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+pub fn day_examples_dir(examples_dir: &Path, day: usize) -> PathBuf {
+    examples_dir.join(format!("{PUZZLE_INPUT_PREFIX}{day}"))
+}
+
+fn decode_utf8(path: &Path, bytes: Vec<u8>) -> Result<String> {
+    String::from_utf8(bytes).map_err(|error| Error::InvalidUtf8 {
+        path: path.to_path_buf(),
+        valid_up_to: error.utf8_error().valid_up_to(),
+    })
+}
+
+// Read a whole file as UTF-8, keeping track of which file failed and why
+pub fn read_input_file(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => Error::MissingFile {
+            path: path.to_path_buf(),
+        },
+        _ => Error::UnreadableFile {
+            path: path.to_path_buf(),
+            source,
+        },
+    })?;
+    decode_utf8(path, bytes)
+}
+
+fn read_stdin() -> Result<String> {
+    let mut bytes = Vec::new();
+    io::stdin()
+        .lock()
+        .read_to_end(&mut bytes)
+        .map_err(|source| Error::UnreadableFile {
+            path: PathBuf::from(STDIN_NAME),
+            source,
+        })?;
+    decode_utf8(Path::new(STDIN_NAME), bytes)
+}
+
+pub fn get_puzzle_input_path(day: usize) -> Result<String> {
+    InputSource::Directory(default_input_dir()).load(day)
+}
+
+pub fn get_puzzle_example_input(day: usize, name: &str) -> Result<String> {
+    read_input_file(
+        &day_examples_dir(&examples_dir(), day).join(format!("{name}{PUZZLE_INPUT_SUFFIX}")),
+    )
+}
 
-    let puzzle_input_path = Path::new(manifest_dir).join(format!(
-        "{PUZZLE_DUMMY_INPUT_PREFIX}{day}{PUZZLE_INPUT_SUFFIX}"
-    ));
-    fs::read(puzzle_input_path)
-        .unwrap_or_else(|_| panic!("Unexpected error reading puzzle input for day {day}"))
+// Iterate over the lines of an input with their 1-based line number, without trailing whitespace
+pub fn numbered_lines(puzzle_input: &str) -> impl Iterator<Item = (usize, &str)> {
+    puzzle_input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim_end()))
 }